@@ -1,10 +1,158 @@
-// CLI module - placeholder
-// This will be implemented in the next phase
+// CLI module - command-line arguments
+use std::time::Duration;
 
-pub struct Cli;
+use crate::models::duration_parser::{parse_duration, DurationParseError};
+use crate::services::config_format::ConfigFormat;
+
+/// One-off action run instead of opening the timer window
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    ListBackups,                 // `--list-backups`
+    RestoreBackup(String),       // `--restore-backup <name or number>`
+    ConvertConfig(ConfigFormat), // `--convert-config <json|toml|yaml>`
+    ShowConfig,                  // `--show-config`: effective settings and where each came from
+}
+
+/// Command-line parsing errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliError {
+    InvalidDuration(DurationParseError),
+    MissingValue(String), // Option that needs an argument
+    UnknownOption(String),
+    UnknownFormat(String), // Not json, toml or yaml
+    InvalidOverride(String), // `--set` value without `=`
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::InvalidDuration(e) => write!(f, "{}", e),
+            CliError::MissingValue(option) => write!(f, "{} needs a value", option),
+            CliError::UnknownOption(option) => write!(f, "Unknown option: {}", option),
+            CliError::UnknownFormat(format) => write!(f, "Unknown config format: {} (use json, toml or yaml)", format),
+            CliError::InvalidOverride(text) => write!(f, "Expected --set key=value, found: {}", text),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<DurationParseError> for CliError {
+    fn from(e: DurationParseError) -> Self {
+        CliError::InvalidDuration(e)
+    }
+}
+
+/// Parsed command-line arguments
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cli {
+    pub start_duration: Option<Duration>, // Countdown to start immediately, e.g., `ghost_timer 25m`
+    pub command: Option<CliCommand>,
+    pub overrides: Vec<String>, // `--set key=value`, applied over every configuration file
+}
 
 impl Cli {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Parse arguments (excluding the program name)
+    ///
+    /// Words that are not options are joined, so `ghost_timer in 10 minutes` works without quoting.
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, CliError> {
+        let mut cli = Self::new();
+        let mut words = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--list-backups" => cli.command = Some(CliCommand::ListBackups),
+                "--show-config" => cli.command = Some(CliCommand::ShowConfig),
+                "--set" => {
+                    let assignment = args.next().ok_or_else(|| CliError::MissingValue(arg.clone()))?;
+                    if !assignment.contains('=') {
+                        return Err(CliError::InvalidOverride(assignment));
+                    }
+                    cli.overrides.push(assignment);
+                }
+                "--restore-backup" => {
+                    let backup = args.next().ok_or_else(|| CliError::MissingValue(arg.clone()))?;
+                    cli.command = Some(CliCommand::RestoreBackup(backup));
+                }
+                "--convert-config" => {
+                    let format = args.next().ok_or_else(|| CliError::MissingValue(arg.clone()))?;
+                    let format = format.parse().map_err(|_| CliError::UnknownFormat(format))?;
+                    cli.command = Some(CliCommand::ConvertConfig(format));
+                }
+                option if option.starts_with("--") => return Err(CliError::UnknownOption(arg)),
+                _ => words.push(arg),
+            }
+        }
+
+        let text = words.join(" ");
+        if !text.trim().is_empty() {
+            cli.start_duration = Some(parse_duration(&text)?);
+        }
+        Ok(cli)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_duration_argument() {
+        assert_eq!(Cli::from_args(args("")).unwrap(), Cli::new());
+        assert_eq!(
+            Cli::from_args(args("in 10 minutes")).unwrap().start_duration,
+            Some(Duration::from_secs(600))
+        );
+        assert!(Cli::from_args(args("tomorrow-ish")).is_err());
+    }
+
+    #[test]
+    fn test_backup_commands() {
+        assert_eq!(Cli::from_args(args("--list-backups")).unwrap().command, Some(CliCommand::ListBackups));
+        assert_eq!(
+            Cli::from_args(args("--restore-backup 2")).unwrap().command,
+            Some(CliCommand::RestoreBackup("2".to_string()))
+        );
+        assert_eq!(
+            Cli::from_args(args("--restore-backup")),
+            Err(CliError::MissingValue("--restore-backup".to_string()))
+        );
+        assert!(matches!(Cli::from_args(args("--frobnicate")), Err(CliError::UnknownOption(_))));
+    }
+
+    #[test]
+    fn test_setting_overrides() {
+        let cli = Cli::from_args(args("--set display.transparency=0.5 --set behavior.overtime_enabled=true 5m")).unwrap();
+        assert_eq!(cli.overrides, vec!["display.transparency=0.5", "behavior.overtime_enabled=true"]);
+        assert_eq!(cli.start_duration, Some(Duration::from_secs(300)));
+        assert_eq!(
+            Cli::from_args(args("--set display.transparency")),
+            Err(CliError::InvalidOverride("display.transparency".to_string()))
+        );
+        assert_eq!(Cli::from_args(args("--show-config")).unwrap().command, Some(CliCommand::ShowConfig));
+    }
+
+    #[test]
+    fn test_convert_command() {
+        assert_eq!(
+            Cli::from_args(args("--convert-config TOML")).unwrap().command,
+            Some(CliCommand::ConvertConfig(ConfigFormat::Toml))
+        );
+        assert_eq!(
+            Cli::from_args(args("--convert-config yml")).unwrap().command,
+            Some(CliCommand::ConvertConfig(ConfigFormat::Yaml))
+        );
+        assert_eq!(
+            Cli::from_args(args("--convert-config ini")),
+            Err(CliError::UnknownFormat("ini".to_string()))
+        );
     }
 }
\ No newline at end of file
@@ -3,24 +3,43 @@
 
 // Module declarations - these will fail until modules are implemented
 pub mod models {
+    pub mod clock;
     pub mod timer;
     pub mod config;
     pub mod display;
     pub mod app_state;
+    pub mod pomodoro;
+    pub mod sequence;
+    pub mod timer_collection;
+    pub mod alarm;
+    pub mod duration_parser;
+    pub mod time_format;
+    pub mod timer_snapshot;
+    pub mod events;
+    pub mod warning;
+    pub mod ringing;
 }
 
 pub mod services {
     pub mod timer_service;
     pub mod window_manager;
     pub mod config_manager;
+    pub mod config_format;
+    pub mod config_layers;
+    pub mod config_migration;
+    pub mod config_salvage;
     pub mod background_detector;
     pub mod hotkey_manager;
+    pub mod session_store;
+    pub mod sound_player;
+    pub mod notifier;
 }
 
 pub mod cli;
 
 // Re-export commonly used types
 pub use models::{
+    clock::{Clock, ManualClock, SharedClock, SystemClock},
     timer::{Timer, TimerControl, TimerState, TimerError},
     config::Configuration,
     app_state::AppState,
@@ -4,30 +4,125 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use eframe::egui;
-use ghost_timer::{AppState, VERSION};
-use std::time::Duration;
+use ghost_timer::{AppState, TimerControl, VERSION};
+use ghost_timer::cli::{Cli, CliCommand};
+use ghost_timer::services::config_manager::{ConfigManager, ConfigManagerImpl, CONFIG_POLL_INTERVAL};
+use ghost_timer::services::config_format::to_plain_value;
+use ghost_timer::services::config_layers::ConfigResolver;
+use ghost_timer::services::session_store::SessionStore;
+use ghost_timer::services::notifier::NotificationDispatcher;
+use ghost_timer::models::events::{SubscriptionId, TimerSource};
+use ghost_timer::models::alarm::{format_alarm_target, parse_alarm_target};
+use ghost_timer::models::duration_parser::{format_duration, parse_duration};
+use ghost_timer::models::config::WarningAction;
+use ghost_timer::models::time_format::{FormatContext, TimeFormat, EDIT_TIME_FORMAT};
+use ghost_timer::models::timer_collection::TimerId;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Number of laps listed under the main display
+const RECENT_LAPS_SHOWN: usize = 3;
+
+/// Window size with only the main timer shown
+const BASE_WINDOW_WIDTH: f32 = 250.0;
+const BASE_WINDOW_HEIGHT: f32 = 120.0;
+
+/// Height of one row in the named timer list
+const NAMED_TIMER_ROW_HEIGHT: f32 = 20.0;
+
+/// How long the widget flashes after a notification
+const FLASH_DURATION: Duration = Duration::from_millis(1500);
 
 fn main() -> Result<(), eframe::Error> {
     println!("GhostTimer v{} starting...", VERSION);
     
+    // An optional duration argument starts a countdown right away
+    let cli = Cli::from_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("Ignoring arguments: {}", e);
+        Cli::new()
+    });
+    if let Some(command) = &cli.command {
+        run_command(command, &cli.overrides);
+        return Ok(());
+    }
+    
+    // Load settings before the window opens so a remembered position applies immediately
+    let manager = ConfigManagerImpl::new();
+    let app_state = match ConfigResolver::standard(manager.path(), &cli.overrides) {
+        Ok(resolver) => AppState::with_layered_config(manager, resolver),
+        Err(e) => {
+            eprintln!("Ignoring configuration overrides: {}", e);
+            AppState::with_config_manager(manager)
+        }
+    };
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size([BASE_WINDOW_WIDTH, BASE_WINDOW_HEIGHT])
+        .with_min_inner_size([200.0, 100.0])
+        .with_transparent(true)
+        .with_decorations(false)
+        .with_always_on_top()
+        .with_resizable(false);
+    if app_state.config.behavior.remember_position {
+        let (x, y) = app_state.window_position();
+        viewport = viewport.with_position([x as f32, y as f32]);
+    }
+    
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([250.0, 120.0])
-            .with_min_inner_size([200.0, 100.0])
-            .with_transparent(true)
-            .with_decorations(false)
-            .with_always_on_top()
-            .with_resizable(false),
+        viewport,
         ..Default::default()
     };
     
     eframe::run_native(
         "GhostTimer",
         options,
-        Box::new(|_cc| Ok(Box::new(TimerApp::new()))),
+        Box::new(move |_cc| {
+            let mut app = TimerApp::new(app_state);
+            if let Some(duration) = cli.start_duration {
+                if let Err(e) = app.app_state.start_timer(duration) {
+                    eprintln!("Cannot start timer: {}", e);
+                }
+            }
+            Ok(Box::new(app))
+        }),
     )
 }
 
+/// Run a one-off command-line action; exits with status 1 if it fails
+fn run_command(command: &CliCommand, overrides: &[String]) {
+    let manager = ConfigManagerImpl::new();
+    let result = match command {
+        CliCommand::ListBackups => manager.list_backups().map(|backups| {
+            if backups.is_empty() {
+                println!("No configuration backups in {}", manager.backup_dir().display());
+            }
+            for (index, backup) in backups.iter().enumerate() {
+                println!("{:>3}  {}", index + 1, backup.name);
+            }
+        }),
+        CliCommand::RestoreBackup(backup) => manager.restore_backup(backup).map(|_| {
+            println!("Restored {} from backup {}", manager.config_path().display(), backup);
+        }),
+        CliCommand::ConvertConfig(format) => manager.convert(*format).map(|converted| {
+            println!("Configuration is now {}", converted.config_path().display());
+        }),
+        CliCommand::ShowConfig => ConfigResolver::standard(manager.path(), overrides)
+            .and_then(|resolver| resolver.resolve())
+            .and_then(|layered| {
+                let values = to_plain_value(&layered.config)?;
+                for (path, layer) in layered.sources() {
+                    let value = path.split('.').try_fold(&values, |value, key| value.get(key));
+                    println!("{} = {}  ({})", path, value.map(|value| value.to_string()).unwrap_or_default(), layer);
+                }
+                Ok(())
+            }),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
 struct TimerApp {
     app_state: AppState,
     timer_minutes: String,
@@ -35,11 +130,26 @@ struct TimerApp {
     last_tick: std::time::Instant,
     is_dragging: bool,
     drag_start_pos: Option<egui::Pos2>,
-    last_timer_state: ghost_timer::models::timer::TimerState,
+    last_window_height: f32,
     is_editing_timer: bool,
     edit_text: String,
     cursor_pos: usize,  // Position in edit_text
     edit_field: EditField, // Whether editing minutes or seconds
+    edit_alarm: bool, // Edit text is an alarm time of day (HH:MM) rather than a duration
+    session_store: SessionStore, // Snapshot of running timers, restored after a restart
+    flash_requested: Arc<AtomicBool>, // Raised by the flash notifier
+    flash_until: Option<std::time::Instant>, // Widget background flashes until then
+    notifications: Option<SubscriptionId>, // Dispatcher built from the current notification settings
+    last_outer_position: Option<(i32, i32)>, // Window position seen on the previous frame
+}
+
+/// Per-row actions in the named timer list
+#[derive(Clone, Copy, PartialEq)]
+enum NamedTimerAction {
+    Focus,
+    Toggle,
+    Reset,
+    Remove,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -49,74 +159,219 @@ enum EditField {
 }
 
 impl TimerApp {
-    fn new() -> Self {
-        Self {
-            app_state: AppState::new(),
+    fn new(app_state: AppState) -> Self {
+        let mut app = Self {
+            app_state,
             timer_minutes: "5".to_string(),
             timer_seconds: "0".to_string(),
             last_tick: std::time::Instant::now(),
             is_dragging: false,
             drag_start_pos: None,
-            last_timer_state: ghost_timer::models::timer::TimerState::Stopped,
+            last_window_height: BASE_WINDOW_HEIGHT,
             is_editing_timer: false,
             edit_text: "05:00".to_string(),
             cursor_pos: 0,
             edit_field: EditField::Minutes,
+            edit_alarm: false,
+            session_store: SessionStore::new(),
+            flash_requested: Arc::new(AtomicBool::new(false)),
+            flash_until: None,
+            notifications: None,
+            last_outer_position: None,
+        };
+        // Subscribe first so countdowns that ran out while closed notify once on startup
+        app.subscribe_notifications();
+        app.restore_session();
+        app
+    }
+    
+    /// Replace the notification dispatcher with one built from the current settings
+    fn subscribe_notifications(&mut self) {
+        if let Some(id) = self.notifications.take() {
+            self.app_state.unsubscribe(id);
         }
+        let dispatcher = NotificationDispatcher::from_config(
+            &self.app_state.config.notifications,
+            self.flash_requested.clone(),
+        );
+        self.notifications = Some(self.app_state.subscribe(Box::new(dispatcher)));
     }
     
-    fn play_notification_sound(&self) {
-        #[cfg(windows)]
-        {
-            use std::thread;
-            thread::spawn(|| {
-                unsafe {
-                    winapi::um::winuser::MessageBeep(0xFFFFFFFF); // Default system sound
-                }
-            });
+    /// Pick up edits made to the config file while running
+    ///
+    /// Transparency and timer settings are read from the configuration as they
+    /// are used; the window position and notification backends need to be pushed
+    /// explicitly. Changed hotkeys are registered again by `AppState::apply_configuration`.
+    fn reload_configuration(&mut self, ctx: &egui::Context) {
+        if !self.app_state.reload_changed_configuration() {
+            return;
+        }
+        self.subscribe_notifications();
+        if self.app_state.config.behavior.remember_position {
+            let (x, y) = self.app_state.window_position();
+            ctx.send_viewport_cmd(egui::ViewportCommand::OuterPosition(egui::pos2(x as f32, y as f32)));
         }
+        ctx.request_repaint();
     }
     
-    fn format_time(duration: Duration) -> String {
-        let total_seconds = duration.as_secs();
-        let minutes = total_seconds / 60;
-        let seconds = total_seconds % 60;
-        format!("{:02}:{:02}", minutes, seconds)
+    /// Bring back the timers of the previous run, if a snapshot was saved
+    fn restore_session(&mut self) {
+        match self.session_store.load() {
+            Ok(Some(snapshot)) => self.app_state.restore_session(&snapshot),
+            Ok(None) => {}
+            Err(e) => eprintln!("Failed to restore timers: {}", e),
+        }
     }
     
-    fn parse_timer_input(&self) -> Option<Duration> {
-        let minutes: u64 = self.timer_minutes.parse().ok()?;
-        let seconds: u64 = self.timer_seconds.parse().ok()?;
-        
-        if minutes > 59 || seconds > 59 {
-            return None;
+    /// Persist the timers after any state transition
+    fn save_session(&mut self) {
+        if !self.app_state.session_dirty {
+            return;
         }
-        
-        Some(Duration::from_secs(minutes * 60 + seconds))
+        if let Err(e) = self.session_store.save(&self.app_state.session_snapshot()) {
+            eprintln!("Failed to save timers: {}", e);
+        }
+        self.app_state.session_dirty = false;
     }
     
-    fn parse_timer_edit_text(&self) -> Option<Duration> {
-        // Parse "mm:ss" format
-        let parts: Vec<&str> = self.edit_text.split(':').collect();
-        if parts.len() != 2 {
-            return None;
+    /// Record where the window was moved to, so it reopens there
+    fn track_window_position(&mut self, ctx: &egui::Context) {
+        if !self.app_state.config.behavior.remember_position {
+            return;
+        }
+        let (outer_rect, minimized) = ctx.input(|i| (i.viewport().outer_rect, i.viewport().minimized));
+        let Some(rect) = outer_rect else {
+            return;
+        };
+        if minimized == Some(true) {
+            return; // Minimized windows report an off-screen position
         }
+        let position = (rect.min.x.round() as i32, rect.min.y.round() as i32);
+        // Only record actual moves, so a position loaded from the file is not
+        // overwritten by the old one before the window gets there
+        if self.last_outer_position.replace(position) == Some(position) {
+            return;
+        }
+        if position != self.app_state.window_position() {
+            let _ = self.app_state.set_window_position(position.0, position.1);
+        }
+    }
+    
+    /// Persist changed settings once the window is no longer being dragged
+    fn save_configuration(&mut self) {
+        if !self.app_state.is_config_dirty() || self.is_dragging {
+            return;
+        }
+        if let Err(e) = self.app_state.save_configuration() {
+            eprintln!("Failed to save settings: {}", e);
+            // Retry only after the next change rather than every frame
+            self.app_state.mark_config_saved();
+        }
+    }
+    
+    /// Compact list of named timers with per-timer controls
+    fn show_named_timers(&mut self, ui: &mut egui::Ui, transparency: f32) {
+        let text_color = egui::Color32::from_rgba_unmultiplied(220, 220, 220, (255.0 * transparency) as u8);
+        let focused = self.app_state.focused_timer;
+        let mut action: Option<(TimerId, NamedTimerAction)> = None;
         
-        let minutes: u64 = parts[0].parse().ok()?;
-        let seconds: u64 = parts[1].parse().ok()?;
+        for named in self.app_state.timers.iter() {
+            let time_text = match named.timer.state() {
+                ghost_timer::models::timer::TimerState::Stopped => Self::format_time(named.preset),
+                ghost_timer::models::timer::TimerState::Finished => "DONE!".to_string(),
+                _ => Self::format_time(named.timer.remaining_time().unwrap_or_default()),
+            };
+            let toggle_icon = if named.timer.state().is_active() { "⏸" } else { "▶" };
+            let marker = if focused == Some(named.id) { "›" } else { " " };
+            
+            ui.horizontal(|ui| {
+                let name_response = ui.add(
+                    egui::Label::new(
+                        egui::RichText::new(format!("{}{:<8.8} {}", marker, named.name, time_text))
+                            .size(12.0)
+                            .color(text_color)
+                            .family(egui::FontFamily::Monospace)
+                    ).sense(egui::Sense::click())
+                );
+                if name_response.clicked() {
+                    action = Some((named.id, NamedTimerAction::Focus));
+                }
+                
+                for (icon, row_action) in [
+                    (toggle_icon, NamedTimerAction::Toggle),
+                    ("⏹", NamedTimerAction::Reset),
+                    ("✕", NamedTimerAction::Remove),
+                ] {
+                    let response = ui.add(
+                        egui::Label::new(egui::RichText::new(icon).size(12.0).color(text_color))
+                            .sense(egui::Sense::click())
+                    );
+                    if response.clicked() {
+                        action = Some((named.id, row_action));
+                    }
+                }
+            });
+        }
         
-        if minutes > 999 || seconds > 59 {
-            return None;
+        // Add another named timer; the first one is added from the main controls
+        if !self.app_state.timers.is_empty() {
+            let add_response = ui.add(
+                egui::Label::new(egui::RichText::new("＋ timer").size(11.0).color(text_color))
+                    .sense(egui::Sense::click())
+            );
+            if add_response.clicked() {
+                self.add_named_timer();
+            }
         }
         
-        Some(Duration::from_secs(minutes * 60 + seconds))
+        if let Some((id, row_action)) = action {
+            let _ = match row_action {
+                NamedTimerAction::Focus => {
+                    // Clicking the focused timer again returns focus to the main timer
+                    let target = if focused == Some(id) { None } else { Some(id) };
+                    self.app_state.focus_timer(target);
+                    Ok(())
+                }
+                NamedTimerAction::Toggle => self.app_state.toggle_named_timer(id),
+                NamedTimerAction::Reset => self.app_state.reset_named_timer(id),
+                NamedTimerAction::Remove => self.app_state.remove_named_timer(id),
+            };
+        }
+    }
+    
+    /// Add a named timer using the current input duration
+    fn add_named_timer(&mut self) {
+        let name = format!("Timer {}", self.app_state.timers.len() + 1);
+        let preset = self.parse_timer_input().unwrap_or(Duration::from_secs(300));
+        let _ = self.app_state.add_named_timer(&name, preset);
+    }
+    
+    /// Plain time for secondary displays (laps, named timers): "mm:ss" or "h:mm:ss"
+    fn format_time(duration: Duration) -> String {
+        format_duration(duration)
+    }
+    
+    fn parse_timer_input(&self) -> Option<Duration> {
+        parse_duration(&format!("{}:{}", self.timer_minutes, self.timer_seconds)).ok()
+    }
+    
+    fn parse_timer_edit_text(&self) -> Option<Duration> {
+        // The editor cycles minutes through 0-999, so keep its input within that range
+        parse_duration(&self.edit_text)
+            .ok()
+            .filter(|duration| duration.as_secs() < 1000 * 60)
+    }
+    
+    /// Resolve the edit text as an alarm time, e.g. "14:30" (next occurrence)
+    fn parse_alarm_edit_text(&self) -> Option<SystemTime> {
+        parse_alarm_target(&self.edit_text).ok()
     }
     
     fn format_edit_time(&self, duration: Duration) -> String {
-        let total_seconds = duration.as_secs();
-        let minutes = total_seconds / 60;
-        let seconds = total_seconds % 60;
-        format!("{:02}:{:02}", minutes, seconds)
+        // The editor always works on fixed-width "mm:ss" text
+        let mut format = TimeFormat::parse_or_default(EDIT_TIME_FORMAT);
+        format.collapse_leading_zeros = false;
+        format.format(&FormatContext { duration, ..Default::default() })
     }
     
     
@@ -171,6 +426,12 @@ impl TimerApp {
     }
     
     fn handle_char_input(&mut self, c: char) {
+        // '@' switches between countdown and alarm entry
+        if c == '@' {
+            self.edit_alarm = !self.edit_alarm;
+            return;
+        }
+        
         if c.is_ascii_digit() {
             let mut chars: Vec<char> = self.edit_text.chars().collect();
             if self.cursor_pos < chars.len() && chars[self.cursor_pos] != ':' {
@@ -188,7 +449,10 @@ impl TimerApp {
                     }
                 }
                 
-                // Validate and fix the input
+                // Validate and fix the input (alarm times are checked on Enter)
+                if self.edit_alarm {
+                    return;
+                }
                 if let Some(duration) = self.parse_timer_edit_text() {
                     self.edit_text = self.format_edit_time(duration);
                 }
@@ -201,29 +465,41 @@ impl eframe::App for TimerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Update timer state
         if self.last_tick.elapsed() >= Duration::from_millis(100) {
+            // Notifications are delivered by NotificationDispatcher as the tick publishes events
             if self.app_state.tick_timer() {
                 // Timer state changed, request repaint
                 ctx.request_repaint();
-                
-                // Check if timer just finished and play notification sound
-                let current_state = self.app_state.timer_state().clone();
-                if matches!(current_state, ghost_timer::models::timer::TimerState::Finished) &&
-                   !matches!(self.last_timer_state, ghost_timer::models::timer::TimerState::Finished) {
-                    self.play_notification_sound();
-                }
-                self.last_timer_state = current_state;
+            }
+            
+            if self.flash_requested.swap(false, Ordering::SeqCst) {
+                self.flash_until = Some(std::time::Instant::now() + FLASH_DURATION);
+                ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(
+                    egui::UserAttentionType::Informational,
+                ));
             }
             self.last_tick = std::time::Instant::now();
+            self.reload_configuration(ctx);
         }
         
         // Get transparency for UI rendering
         let transparency = self.app_state.window_transparency();
         
+        // Flash the background between dark and amber a few times per second
+        let flashing = self.flash_until.is_some_and(|until| std::time::Instant::now() < until);
+        if flashing {
+            ctx.request_repaint();
+        }
+        let background = if flashing && (ctx.input(|i| i.time) * 4.0) as i64 % 2 == 0 {
+            egui::Color32::from_rgba_unmultiplied(160, 100, 20, (255.0 * transparency.max(0.8)) as u8)
+        } else {
+            egui::Color32::from_rgba_unmultiplied(40, 40, 40, (255.0 * transparency) as u8)
+        };
+        
         // Main UI
         egui::CentralPanel::default()
             .frame(
                 egui::Frame::default()
-                    .fill(egui::Color32::from_rgba_unmultiplied(40, 40, 40, (255.0 * transparency) as u8))
+                    .fill(background)
                     .corner_radius(8.0)
                     .inner_margin(12.0)
             )
@@ -235,6 +511,27 @@ impl eframe::App for TimerApp {
                     
                     ui.add_space(5.0);
                     
+                    // Pomodoro phase or sequence step label above the main display
+                    let phase_label = self.app_state.pomodoro.as_ref()
+                        .map(|session| session.display_label())
+                        .or_else(|| self.app_state.sequence.as_ref().map(|run| run.display_label()));
+                    if let Some(label) = phase_label {
+                        ui.label(
+                            egui::RichText::new(label)
+                                .size(12.0)
+                                .color(egui::Color32::from_rgba_unmultiplied(200, 200, 200, (255.0 * transparency * 0.8) as u8))
+                        );
+                    }
+                    
+                    // Alarm target above the countdown to it
+                    if let Some(target) = self.app_state.alarm_target().filter(|_| !is_stopped) {
+                        ui.label(
+                            egui::RichText::new(format!("⏰ {}", format_alarm_target(target)))
+                                .size(12.0)
+                                .color(egui::Color32::from_rgba_unmultiplied(200, 200, 200, (255.0 * transparency * 0.8) as u8))
+                        );
+                    }
+                    
                     // Timer display - editable when stopped, showing time when running
                     if is_stopped && self.is_editing_timer {
                         // Custom edit mode - looks exactly like display but handles input
@@ -308,7 +605,12 @@ impl eframe::App for TimerApp {
                         
                         // Show help text
                         ui.add_space(2.0);
-                        ui.label(egui::RichText::new("Type numbers, ↑↓ to change, ←→ to switch field")
+                        let help_text = if self.edit_alarm {
+                            "Alarm at HH:MM, Enter to start, @ for countdown"
+                        } else {
+                            "Type numbers, ↑↓ to change, ←→ to switch field, @ for alarm"
+                        };
+                        ui.label(egui::RichText::new(help_text)
                             .size(10.0)
                             .color(egui::Color32::from_rgba_unmultiplied(200, 200, 200, (255.0 * transparency * 0.8) as u8))
                         );
@@ -316,8 +618,14 @@ impl eframe::App for TimerApp {
                         // Exit edit mode on Enter or Escape
                         if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
                             self.is_editing_timer = false;
-                            // Update timer values from edit text
-                            if let Some(duration) = self.parse_timer_edit_text() {
+                            if self.edit_alarm {
+                                // Alarms start right away, counting down to the entered time
+                                self.edit_alarm = false;
+                                if let Some(target) = self.parse_alarm_edit_text() {
+                                    let _ = self.app_state.start_alarm(target);
+                                }
+                            } else if let Some(duration) = self.parse_timer_edit_text() {
+                                // Update timer values from edit text
                                 let total_seconds = duration.as_secs();
                                 self.timer_minutes = (total_seconds / 60).to_string();
                                 self.timer_seconds = (total_seconds % 60).to_string();
@@ -326,6 +634,7 @@ impl eframe::App for TimerApp {
                         
                         if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
                             self.is_editing_timer = false;
+                            self.edit_alarm = false;
                             // Reset edit text to current timer values
                             if let Some(duration) = self.parse_timer_input() {
                                 self.edit_text = self.format_edit_time(duration);
@@ -344,30 +653,63 @@ impl eframe::App for TimerApp {
                             ghost_timer::models::timer::TimerState::Finished => {
                                 "DONE!".to_string()
                             }
+                            ghost_timer::models::timer::TimerState::Overtime { .. } => {
+                                format!("+{}", self.app_state.format_time(self.app_state.overtime().unwrap_or_default()))
+                            }
+                            state if state.is_stopwatch() => {
+                                // Stopwatch counts up through the same formatter
+                                self.app_state.format_time(self.app_state.elapsed_time().unwrap_or_default())
+                            }
                             _ => {
                                 if let Some(remaining) = self.app_state.remaining_time() {
-                                    Self::format_time(remaining)
+                                    self.app_state.format_time(remaining)
                                 } else {
                                     "00:00".to_string()
                                 }
                             }
                         };
                         
+                        // Overtime and configured pre-finish warnings tint the display
+                        let warning = self.app_state.active_warning();
+                        let mut time_color = if self.app_state.overtime().is_some()
+                            || warning.is_some_and(|w| w.has_action(WarningAction::Color))
+                        {
+                            egui::Color32::from_rgb(255, 140, 0)
+                        } else {
+                            egui::Color32::WHITE
+                        };
+                        if warning.is_some_and(|w| w.has_action(WarningAction::Pulse)) || self.app_state.is_ringing_for(TimerSource::Main) {
+                            let phase = (ui.input(|i| i.time) * std::f64::consts::TAU).sin() as f32;
+                            time_color = time_color.gamma_multiply(0.65 + 0.35 * phase);
+                            ctx.request_repaint();
+                        }
+                        
                         let timer_response = ui.add(
                             egui::Label::new(
                                 egui::RichText::new(&time_text)
                                     .size(32.0)
-                                    .color(egui::Color32::WHITE)
+                                    .color(time_color)
                                     .family(egui::FontFamily::Monospace)
                             ).sense(egui::Sense::click())
                         );
                         
+                        // Mouse wheel over the display adds or removes the adjustment step
+                        if timer_response.hovered() && !is_stopped {
+                            let scroll = ui.input(|i| i.raw_scroll_delta.y);
+                            if scroll > 0.0 {
+                                let _ = self.app_state.add_time(self.app_state.time_adjust_step());
+                            } else if scroll < 0.0 {
+                                let _ = self.app_state.subtract_time(self.app_state.time_adjust_step());
+                            }
+                        }
+                        
                         // Enter edit mode on click (only when stopped)
                         if timer_response.clicked() && is_stopped {
                             self.is_editing_timer = true;
                             self.edit_text = time_text;
                             self.cursor_pos = 0;
                             self.edit_field = EditField::Minutes;
+                            self.edit_alarm = false;
                         }
                         
                         // Show click hint when hovered and stopped
@@ -378,8 +720,53 @@ impl eframe::App for TimerApp {
                                 egui::Color32::from_rgba_unmultiplied(255, 255, 255, (255.0 * transparency * 0.1) as u8)
                             );
                         }
+                        
+                        // Show the most recent laps under the main display
+                        for lap in self.app_state.timer.recent_laps(RECENT_LAPS_SHOWN) {
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "#{:<3} {}  +{}",
+                                    lap.index,
+                                    Self::format_time(lap.split),
+                                    Self::format_time(lap.lap)
+                                ))
+                                    .size(11.0)
+                                    .color(egui::Color32::from_rgba_unmultiplied(200, 200, 200, (255.0 * transparency * 0.8) as u8))
+                                    .family(egui::FontFamily::Monospace)
+                            );
+                        }
                     }
-                    
+
+                    // Settings reset while recovering a damaged config file; click to dismiss
+                    if !self.app_state.config_notices.is_empty() {
+                        let notice_response = ui.add(
+                            egui::Label::new(
+                                egui::RichText::new("⚠ Settings repaired")
+                                    .size(11.0)
+                                    .color(egui::Color32::from_rgba_unmultiplied(255, 200, 80, (255.0 * transparency) as u8))
+                            ).sense(egui::Sense::click())
+                        ).on_hover_text(self.app_state.config_notices.join("\n"));
+
+                        if notice_response.clicked() {
+                            self.app_state.config_notices.clear();
+                        }
+                    }
+
+                    // Outside edit of the config file that was rejected; click to dismiss
+                    if let Some(error) = &self.app_state.config_error {
+                        let error_response = ui.add(
+                            egui::Label::new(
+                                egui::RichText::new("⚠ Settings edit rejected")
+                                    .size(11.0)
+                                    .color(egui::Color32::from_rgba_unmultiplied(255, 110, 90, (255.0 * transparency) as u8))
+                            ).sense(egui::Sense::click())
+                        ).on_hover_text(error.as_str());
+
+                        if error_response.clicked() {
+                            self.app_state.config_error = None;
+                        }
+                    }
+
                     ui.add_space(8.0);
                     
                     // Handle mouse hover for controls visibility
@@ -396,7 +783,21 @@ impl eframe::App for TimerApp {
 
                         match self.app_state.timer_state() {
                             ghost_timer::models::timer::TimerState::Stopped => {
-                                ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+                                ui.horizontal(|ui| {
+                                    // Calculate centering - approximate button width is 24.0 + padding
+                                    let button_width = 24.0;
+                                    let spacing = 10.0;
+                                    let show_sequence = !self.app_state.config.sequences.is_empty();
+                                    let show_add_timer = self.app_state.timers.is_empty();
+                                    let button_count = 2.0 + show_sequence as u8 as f32 + show_add_timer as u8 as f32;
+                                    let total_buttons_width = button_width * button_count + spacing * (button_count - 1.0);
+                                    let available_width = ui.available_width();
+                                    let left_padding = (available_width - total_buttons_width) / 2.0;
+
+                                    if left_padding > 0.0 {
+                                        ui.add_space(left_padding);
+                                    }
+
                                     let start_response = ui.add(
                                         egui::Label::new(
                                             egui::RichText::new("▶")
@@ -416,12 +817,86 @@ impl eframe::App for TimerApp {
 
                                     if start_response.clicked() {
                                         if let Some(duration) = self.parse_timer_input() {
-                                            let _ = self.app_state.start_timer(duration);
+                                            let _ = self.app_state.start_default(duration);
+                                        }
+                                    }
+
+                                    ui.add_space(spacing);
+
+                                    // Stopwatch mode - count up from zero
+                                    let stopwatch_response = ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new("⏱")
+                                                .color(button_text_color)
+                                                .size(24.0)
+                                        ).sense(egui::Sense::click())
+                                    );
+
+                                    if stopwatch_response.hovered() {
+                                        ui.painter().rect_filled(
+                                            stopwatch_response.rect,
+                                            2.0,
+                                            egui::Color32::from_rgba_unmultiplied(255, 255, 255, (255.0 * transparency * 0.1) as u8)
+                                        );
+                                    }
+
+                                    if stopwatch_response.clicked() {
+                                        let _ = self.app_state.start_stopwatch();
+                                    }
+
+                                    // Saved sequence - run the first interval program
+                                    if show_sequence {
+                                        ui.add_space(spacing);
+
+                                        let sequence_response = ui.add(
+                                            egui::Label::new(
+                                                egui::RichText::new("☰")
+                                                    .color(button_text_color)
+                                                    .size(24.0)
+                                            ).sense(egui::Sense::click())
+                                        );
+
+                                        if sequence_response.hovered() {
+                                            ui.painter().rect_filled(
+                                                sequence_response.rect,
+                                                2.0,
+                                                egui::Color32::from_rgba_unmultiplied(255, 255, 255, (255.0 * transparency * 0.1) as u8)
+                                            );
+                                        }
+
+                                        if sequence_response.clicked() {
+                                            let _ = self.app_state.start_saved_sequence(0);
+                                        }
+                                    }
+
+                                    // First named timer - later ones are added below the list
+                                    if show_add_timer {
+                                        ui.add_space(spacing);
+
+                                        let add_timer_response = ui.add(
+                                            egui::Label::new(
+                                                egui::RichText::new("＋")
+                                                    .color(button_text_color)
+                                                    .size(24.0)
+                                            ).sense(egui::Sense::click())
+                                        ).on_hover_text("Add a named timer");
+
+                                        if add_timer_response.hovered() {
+                                            ui.painter().rect_filled(
+                                                add_timer_response.rect,
+                                                2.0,
+                                                egui::Color32::from_rgba_unmultiplied(255, 255, 255, (255.0 * transparency * 0.1) as u8)
+                                            );
+                                        }
+
+                                        if add_timer_response.clicked() {
+                                            self.add_named_timer();
                                         }
                                     }
                                 });
                             }
-                            ghost_timer::models::timer::TimerState::Running { .. } => {
+                            ghost_timer::models::timer::TimerState::Running { .. }
+                            | ghost_timer::models::timer::TimerState::StopwatchRunning { .. } => {
                                 ui.horizontal(|ui| {
                                     // Calculate centering - approximate button width is 24.0 + padding
                                     let button_width = 24.0;
@@ -477,7 +952,8 @@ impl eframe::App for TimerApp {
                                     }
                                 });
                             }
-                            ghost_timer::models::timer::TimerState::Paused { .. } => {
+                            ghost_timer::models::timer::TimerState::Paused { .. }
+                            | ghost_timer::models::timer::TimerState::StopwatchPaused { .. } => {
                                 ui.horizontal(|ui| {
                                     // Calculate centering - approximate button width is 24.0 + padding
                                     let button_width = 24.0;
@@ -533,15 +1009,94 @@ impl eframe::App for TimerApp {
                                     }
                                 });
                             }
+                            ghost_timer::models::timer::TimerState::Finished if self.app_state.is_ringing_for(TimerSource::Main) => {
+                                // Ringing alarm waits for snooze or acknowledge
+                                ui.horizontal(|ui| {
+                                    let button_width = 24.0;
+                                    let spacing = 10.0;
+                                    let total_buttons_width = button_width * 2.0 + spacing;
+                                    let available_width = ui.available_width();
+                                    let left_padding = (available_width - total_buttons_width) / 2.0;
+
+                                    if left_padding > 0.0 {
+                                        ui.add_space(left_padding);
+                                    }
+
+                                    let snooze_response = ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new("💤")
+                                                .color(button_text_color)
+                                                .size(24.0)
+                                        ).sense(egui::Sense::click())
+                                    ).on_hover_text(format!("Snooze {}", Self::format_time(self.app_state.snooze_duration())));
+
+                                    if snooze_response.clicked() {
+                                        let _ = self.app_state.snooze_alarm_for(TimerSource::Main);
+                                    }
+
+                                    ui.add_space(spacing);
+
+                                    let acknowledge_response = ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new("✓")
+                                                .color(button_text_color)
+                                                .size(24.0)
+                                        ).sense(egui::Sense::click())
+                                    );
+
+                                    if acknowledge_response.clicked() {
+                                        self.app_state.acknowledge_alarm_for(TimerSource::Main);
+                                    }
+                                });
+                            }
                             ghost_timer::models::timer::TimerState::Finished => {
-                                // Auto-reset when finished - no button needed
+                                // Auto-reset when finished and not ringing - no button needed
                                 self.app_state.reset_timer();
                             }
+                            ghost_timer::models::timer::TimerState::Overtime { .. } => {
+                                ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+                                    let dismiss_response = ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new("✓")
+                                                .color(button_text_color)
+                                                .size(24.0)
+                                        ).sense(egui::Sense::click())
+                                    );
+
+                                    if dismiss_response.hovered() {
+                                        ui.painter().rect_filled(
+                                            dismiss_response.rect,
+                                            2.0,
+                                            egui::Color32::from_rgba_unmultiplied(255, 255, 255, (255.0 * transparency * 0.1) as u8)
+                                        );
+                                    }
+
+                                    // Overtime keeps counting until dismissed
+                                    if dismiss_response.clicked() {
+                                        self.app_state.dismiss_overtime();
+                                    }
+                                });
+                            }
                         }
                     }
+                    
+                    // Additional named timers stacked below the main timer
+                    self.show_named_timers(ui, transparency);
                 });
             });
         
+        // Grow the window to fit named timers (plus their add row) and laps
+        let extra_rows = match self.app_state.timers.len() {
+            0 => 0,
+            count => count + 1,
+        };
+        let lap_rows = self.app_state.timer.laps.len().min(RECENT_LAPS_SHOWN);
+        let window_height = BASE_WINDOW_HEIGHT + extra_rows as f32 * NAMED_TIMER_ROW_HEIGHT + lap_rows as f32 * 16.0;
+        if (window_height - self.last_window_height).abs() > f32::EPSILON {
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(BASE_WINDOW_WIDTH, window_height)));
+            self.last_window_height = window_height;
+        }
+        
         // Handle global keyboard shortcuts (when app is focused and not editing)
         if !self.is_editing_timer {
             ctx.input(|i| {
@@ -550,21 +1105,29 @@ impl eframe::App for TimerApp {
                     match self.app_state.timer_state() {
                         ghost_timer::models::timer::TimerState::Stopped => {
                             if let Some(duration) = self.parse_timer_input() {
-                                let _ = self.app_state.start_timer(duration);
+                                let _ = self.app_state.start_default(duration);
                             }
                         }
-                        ghost_timer::models::timer::TimerState::Running { .. } => {
+                        ghost_timer::models::timer::TimerState::Running { .. }
+                        | ghost_timer::models::timer::TimerState::StopwatchRunning { .. } => {
                             let _ = self.app_state.pause_timer();
                         }
-                        ghost_timer::models::timer::TimerState::Paused { .. } => {
+                        ghost_timer::models::timer::TimerState::Paused { .. }
+                        | ghost_timer::models::timer::TimerState::StopwatchPaused { .. } => {
                             let _ = self.app_state.resume_timer();
                         }
+                        ghost_timer::models::timer::TimerState::Finished if self.app_state.is_ringing_for(TimerSource::Main) => {
+                            self.app_state.acknowledge_alarm_for(TimerSource::Main);
+                        }
                         ghost_timer::models::timer::TimerState::Finished => {
                             // Already auto-resets, but allow manual start
                             if let Some(duration) = self.parse_timer_input() {
-                                let _ = self.app_state.start_timer(duration);
+                                let _ = self.app_state.start_default(duration);
                             }
                         }
+                        ghost_timer::models::timer::TimerState::Overtime { .. } => {
+                            self.app_state.dismiss_overtime();
+                        }
                     }
                 }
 
@@ -572,6 +1135,35 @@ impl eframe::App for TimerApp {
                 if i.key_pressed(egui::Key::Space) && i.modifiers.ctrl {
                     self.app_state.reset_timer();
                 }
+
+                // +/- keys: Add or remove the configured adjustment step on the hotkey target
+                if i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals) {
+                    let _ = self.app_state.add_time_to_target(self.app_state.time_adjust_step());
+                }
+                if i.key_pressed(egui::Key::Minus) {
+                    let _ = self.app_state.subtract_time_from_target(self.app_state.time_adjust_step());
+                }
+
+                // L key: Record lap
+                if i.key_pressed(egui::Key::L) {
+                    let _ = self.app_state.record_lap();
+                }
+
+                // N/B keys: Next/previous sequence step
+                if i.key_pressed(egui::Key::N) {
+                    let _ = self.app_state.skip_step();
+                }
+                if i.key_pressed(egui::Key::B) {
+                    let _ = self.app_state.previous_step();
+                }
+
+                // Z/Escape keys: Snooze or acknowledge a ringing alarm
+                if i.key_pressed(egui::Key::Z) {
+                    let _ = self.app_state.snooze_alarm();
+                }
+                if i.key_pressed(egui::Key::Escape) {
+                    self.app_state.acknowledge_alarm();
+                }
             });
         }
 
@@ -596,10 +1188,17 @@ impl eframe::App for TimerApp {
             self.drag_start_pos = None;
         }
         
-        // Request repaint for smooth timer updates
-        if !matches!(self.app_state.timer_state(), ghost_timer::models::timer::TimerState::Stopped) {
+        // Keep ticking while anything is counting, ringing or flashing
+        if self.app_state.needs_ticking() || flashing || self.flash_requested.load(Ordering::SeqCst) {
             ctx.request_repaint_after(Duration::from_millis(100));
         }
+        // Otherwise wake up often enough to notice edits to the config file
+        ctx.request_repaint_after(CONFIG_POLL_INTERVAL);
+        
+        // Save after both ticks and user actions from this frame
+        self.save_session();
+        self.track_window_position(ctx);
+        self.save_configuration();
         
         // Show notification when timer finishes
         if self.app_state.was_notification_triggered() {
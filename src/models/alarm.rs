@@ -0,0 +1,140 @@
+// Alarm targets: resolving "14:30" or "2026-10-20 09:00" to an absolute time
+use chrono::{DateTime, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use std::time::SystemTime;
+
+/// Alarm parsing errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlarmError {
+    InvalidFormat(String),
+    NonexistentLocalTime(String), // Falls in a DST gap
+    InPast(String),
+}
+
+impl std::fmt::Display for AlarmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlarmError::InvalidFormat(text) => {
+                write!(f, "Invalid alarm time: '{}' (expected HH:MM or YYYY-MM-DD HH:MM)", text)
+            }
+            AlarmError::NonexistentLocalTime(text) => {
+                write!(f, "Alarm time '{}' does not exist in the local time zone", text)
+            }
+            AlarmError::InPast(text) => write!(f, "Alarm time '{}' is in the past", text),
+        }
+    }
+}
+
+impl std::error::Error for AlarmError {}
+
+/// Resolve alarm text against the local time zone
+pub fn parse_alarm_target(text: &str) -> Result<SystemTime, AlarmError> {
+    resolve_alarm_target(text, &Local::now()).map(SystemTime::from)
+}
+
+/// Resolve alarm text relative to `now` in any time zone
+///
+/// A bare time of day refers to its next occurrence, so "08:00" typed in the
+/// evening means tomorrow morning. Ambiguous local times (when clocks fall back)
+/// resolve to the earlier instant.
+pub fn resolve_alarm_target<Tz: TimeZone>(
+    text: &str,
+    now: &DateTime<Tz>,
+) -> Result<DateTime<Tz>, AlarmError> {
+    let text = text.trim();
+    let text = text.strip_prefix('@').unwrap_or(text).trim();
+
+    if let Ok(time) = NaiveTime::parse_from_str(text, "%H:%M") {
+        let today = now.date_naive();
+        let target = localize(today.and_time(time), now, text)?;
+        if target > *now {
+            return Ok(target);
+        }
+
+        let tomorrow = today
+            .succ_opt()
+            .ok_or_else(|| AlarmError::InvalidFormat(text.to_string()))?;
+        return localize(tomorrow.and_time(time), now, text);
+    }
+
+    let date_time = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M"))
+        .map_err(|_| AlarmError::InvalidFormat(text.to_string()))?;
+    let target = localize(date_time, now, text)?;
+    if target <= *now {
+        return Err(AlarmError::InPast(text.to_string()));
+    }
+    Ok(target)
+}
+
+/// Format an alarm target for display: time only if today, otherwise with the date
+pub fn format_alarm_target(target: SystemTime) -> String {
+    let target: DateTime<Local> = target.into();
+    let today: NaiveDate = Local::now().date_naive();
+    if target.date_naive() == today {
+        target.format("%H:%M").to_string()
+    } else {
+        target.format("%b %d %H:%M").to_string()
+    }
+}
+
+fn localize<Tz: TimeZone>(
+    local: NaiveDateTime,
+    now: &DateTime<Tz>,
+    text: &str,
+) -> Result<DateTime<Tz>, AlarmError> {
+    match now.timezone().from_local_datetime(&local) {
+        LocalResult::Single(target) => Ok(target),
+        LocalResult::Ambiguous(earliest, _) => Ok(earliest),
+        LocalResult::None => Err(AlarmError::NonexistentLocalTime(text.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+
+    fn at(text: &str) -> DateTime<Utc> {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn test_time_of_day_uses_next_occurrence() {
+        let now = at("2026-10-16 10:00");
+        assert_eq!(resolve_alarm_target("14:30", &now).unwrap(), at("2026-10-16 14:30"));
+        assert_eq!(resolve_alarm_target("@08:00", &now).unwrap(), at("2026-10-17 08:00"));
+    }
+
+    #[test]
+    fn test_full_date_time() {
+        let now = at("2026-10-16 10:00");
+        assert_eq!(
+            resolve_alarm_target("2026-10-20 09:00", &now).unwrap(),
+            at("2026-10-20 09:00")
+        );
+        assert!(matches!(
+            resolve_alarm_target("2026-10-01 09:00", &now),
+            Err(AlarmError::InPast(_))
+        ));
+    }
+
+    #[test]
+    fn test_offset_zone_is_honored() {
+        let zone = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+        let now = at("2026-10-16 00:00").with_timezone(&zone); // 09:00 local
+        let target = resolve_alarm_target("14:30", &now).unwrap();
+        assert_eq!(target.with_timezone(&Utc), at("2026-10-16 05:30"));
+    }
+
+    #[test]
+    fn test_invalid_text() {
+        let now = at("2026-10-16 10:00");
+        assert!(matches!(
+            resolve_alarm_target("25:99", &now),
+            Err(AlarmError::InvalidFormat(_))
+        ));
+        assert!(resolve_alarm_target("soon", &now).is_err());
+    }
+}
\ No newline at end of file
@@ -1,11 +1,27 @@
 // Application state and UI state models
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use crate::models::{
-    timer::{Timer, TimerControl, TimerState},
-    config::Configuration,
+    clock::{SharedClock, SystemClock},
+    timer::{Lap, Timer, TimerControl, TimerError, TimerNotifications, TimerState},
+    config::{ConfigError, Configuration, HotkeyConfig},
+    events::{EventBus, SubscriptionId, TimerEvent, TimerEventKind, TimerSource},
+    duration_parser::parse_duration,
+    time_format::FormatContext,
+    timer_snapshot::{to_epoch_ms, to_ms, NamedTimerSnapshot, SessionSnapshot},
     display::DisplayContext,
+    pomodoro::{PomodoroPhase, PomodoroSession},
+    sequence::{SequenceRun, TimerSequence},
+    timer_collection::{NamedTimer, TimerCollection, TimerId},
+    warning::{Warning, WarningTracker},
+    ringing::{RingStep, Ringing, MAX_RING_DURATION, RING_INTERVAL, SNOOZE_DURATION},
 };
+use crate::services::config_manager::{ConfigManager, ConfigManagerImpl, ConfigWatcher};
+use crate::services::config_layers::{ConfigResolver, LayeredConfig};
+use crate::services::hotkey_manager::HotkeyManagerImpl;
+
+/// Amount added or removed by a single time adjustment shortcut when the configured step is invalid
+pub const TIME_ADJUST_STEP: Duration = Duration::from_secs(60);
 
 /// Overall application runtime state and event handling
 #[derive(Debug)]
@@ -17,6 +33,24 @@ pub struct AppState {
     pub is_running: bool,
     pub notification_triggered: bool,
     pub config_dirty: bool, // Tracks if config needs saving
+    pub pomodoro: Option<PomodoroSession>, // Active Pomodoro cycle, if any
+    pub sequence: Option<SequenceRun>,     // Active interval program, if any
+    pub timers: TimerCollection,           // Additional named timers
+    pub focused_timer: Option<TimerId>,    // Named timer selected in the UI
+    pub recent_timer: Option<TimerId>,     // Named timer most recently controlled
+    pub session_dirty: bool,               // Timer state changed since the last snapshot was saved
+    pub ringing: Vec<Ringing>,             // Finished alarms repeating until acknowledged, oldest first
+    pub config_notices: Vec<String>,       // Settings reset while loading a damaged config, until dismissed
+    pub config_error: Option<String>,      // Why the last outside edit of the config file was rejected
+    events: EventBus,
+    published_state: TimerState, // Main timer state as last reported to subscribers
+    warnings: WarningTracker,    // Pre-finish thresholds for the main countdown
+    config_manager: Option<ConfigManagerImpl>, // Where configuration is persisted, if anywhere
+    config_watcher: Option<ConfigWatcher>,     // Notices outside edits of the persisted configuration
+    config_resolver: Option<ConfigResolver>,   // Layers the configuration is resolved from, if layered
+    config_layers: Option<LayeredConfig>,      // Last resolution, with the source of each setting
+    hotkey_manager: HotkeyManagerImpl,         // Registers the configured hotkeys
+    clock: SharedClock,
 }
 
 /// UI-specific state information
@@ -30,48 +64,387 @@ pub struct UiState {
     pub settings_window_open: bool,
     pub controls_visible: bool,
     pub last_interaction: Option<std::time::Instant>,
+    clock: SharedClock,
 }
 
 impl AppState {
     /// Create a new application state with default values
     pub fn new() -> Self {
-        Self {
-            timer: Timer::new(),
+        Self::with_clock(SystemClock::shared())
+    }
+    
+    /// Create a new application state whose timer and UI share the given clock
+    pub fn with_clock(clock: SharedClock) -> Self {
+        let mut state = Self {
+            timer: Timer::with_clock(clock.clone()),
             config: Configuration::default(),
             display_context: DisplayContext::new(),
-            ui_state: UiState::new(),
+            ui_state: UiState::with_clock(clock.clone()),
             is_running: true,
             notification_triggered: false,
             config_dirty: false,
+            pomodoro: None,
+            sequence: None,
+            timers: TimerCollection::new(clock.clone()),
+            focused_timer: None,
+            recent_timer: None,
+            session_dirty: false,
+            ringing: Vec::new(),
+            config_notices: Vec::new(),
+            config_error: None,
+            events: EventBus::new(),
+            published_state: TimerState::Stopped,
+            warnings: WarningTracker::default(),
+            config_manager: None,
+            config_watcher: None,
+            config_resolver: None,
+            config_layers: None,
+            hotkey_manager: HotkeyManagerImpl::new(),
+            clock,
+        };
+        state.sync_timer_settings();
+        state.hotkey_manager.register(&state.config.hotkeys);
+        state
+    }
+    
+    /// Create application state that loads its configuration from, and saves it to, the given manager
+    ///
+    /// A missing file yields the defaults. A damaged one is repaired, keeping every
+    /// valid setting, and what was reset is listed in `config_notices`.
+    pub fn with_config_manager(manager: ConfigManagerImpl) -> Self {
+        let mut state = Self::new();
+        state.config_watcher = Some(manager.watcher());
+        state.config_manager = Some(manager);
+        if let Err(e) = state.load_configuration() {
+            eprintln!("Cannot load configuration, using defaults: {}", e);
         }
+        state
+    }
+    
+    /// Create application state whose configuration is resolved from layers
+    ///
+    /// `manager` is the resolver's user file, which changes made in the app are
+    /// saved to. If the layers do not resolve, the reason is shown in
+    /// `config_notices` and the user file is used on its own; see `load_configuration`.
+    pub fn with_layered_config(manager: ConfigManagerImpl, resolver: ConfigResolver) -> Self {
+        let mut state = Self::new();
+        state.config_watcher = Some(manager.watcher());
+        state.config_manager = Some(manager);
+        state.config_resolver = Some(resolver);
+        if let Err(e) = state.load_configuration() {
+            eprintln!("Cannot load configuration, using defaults: {}", e);
+            state.config_notices.push(format!("Configuration not loaded, using defaults: {}", e));
+        }
+        state
+    }
+    
+    /// Clock shared by the timer and UI state
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
     }
     
     /// Start a timer with the specified duration
     pub fn start_timer(&mut self, duration: Duration) -> Result<(), Box<dyn std::error::Error>> {
         let result = self.timer.start(duration)?;
-        self.mark_interaction();
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Started { duration: Some(duration) });
         Ok(result)
     }
     
+    /// Amount a single add/subtract time action adjusts by, from configuration
+    pub fn time_adjust_step(&self) -> Duration {
+        Self::configured_duration(&self.config.behavior.time_adjust_step, TIME_ADJUST_STEP)
+    }
+    
+    /// Parse a duration setting, falling back when it is invalid or zero
+    fn configured_duration(text: &str, fallback: Duration) -> Duration {
+        parse_duration(text)
+            .ok()
+            .filter(|duration| !duration.is_zero())
+            .unwrap_or(fallback)
+    }
+    
+    /// Count down to an absolute wall-clock time
+    pub fn start_alarm(&mut self, target: SystemTime) -> Result<(), Box<dyn std::error::Error>> {
+        self.timer.start_alarm(target)?;
+        self.pomodoro = None;
+        self.sequence = None;
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        let duration = Some(self.timer.original_duration);
+        self.emit(TimerSource::Main, TimerEventKind::Started { duration });
+        Ok(())
+    }
+    
+    /// Wall-clock time the current alarm ends at, if the timer is in alarm mode
+    pub fn alarm_target(&self) -> Option<SystemTime> {
+        self.timer.alarm_target
+    }
+    
+    /// Start a Pomodoro session with the configured phase lengths
+    pub fn start_pomodoro(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let session = PomodoroSession::new(self.config.pomodoro.clone());
+        let duration = session.current_duration();
+        self.timer.start(duration)?;
+        self.pomodoro = Some(session);
+        self.sequence = None;
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Started { duration: Some(duration) });
+        Ok(())
+    }
+    
+    /// Start either a Pomodoro session or a plain countdown, depending on configuration
+    pub fn start_default(&mut self, duration: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        if self.config.pomodoro.enabled {
+            self.start_pomodoro()
+        } else {
+            self.start_timer(duration)
+        }
+    }
+    
+    /// Get the current Pomodoro phase, if a session is active
+    pub fn pomodoro_phase(&self) -> Option<PomodoroPhase> {
+        self.pomodoro.as_ref().map(|session| session.phase)
+    }
+    
+    /// Start running a multi-step sequence from its first step
+    pub fn start_sequence(&mut self, sequence: TimerSequence) -> Result<(), Box<dyn std::error::Error>> {
+        let errors = sequence.validate(0);
+        if let Some(error) = errors.first() {
+            return Err(error.to_string().into());
+        }
+        
+        let run = SequenceRun::new(sequence);
+        let duration = run.current_step().duration();
+        self.timer.start(duration)?;
+        self.sequence = Some(run);
+        self.pomodoro = None;
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Started { duration: Some(duration) });
+        Ok(())
+    }
+    
+    /// Start one of the sequences saved in the configuration
+    pub fn start_saved_sequence(&mut self, index: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = self.config.sequences.get(index)
+            .cloned()
+            .ok_or("No saved sequence at that index")?;
+        self.start_sequence(sequence)
+    }
+    
+    /// Skip to the next step of the active sequence
+    pub fn skip_step(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let run = self.sequence.as_mut().ok_or("No sequence is running")?;
+        match run.advance().map(|step| step.duration()) {
+            Some(duration) => {
+                self.timer.reset();
+                self.timer.start(duration)?;
+            }
+            None => {
+                // Skipped past the final step - the program is over
+                self.timer.reset();
+                self.sequence = None;
+            }
+        }
+        self.mark_timer_changed();
+        self.emit_step_change(false);
+        Ok(())
+    }
+    
+    /// Go back to the previous step of the active sequence
+    pub fn previous_step(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let run = self.sequence.as_mut().ok_or("No sequence is running")?;
+        let duration = run.previous().duration();
+        self.timer.reset();
+        self.timer.start(duration)?;
+        self.mark_timer_changed();
+        self.emit_step_change(false);
+        Ok(())
+    }
+    
+    /// Start counting up from zero (stopwatch mode)
+    pub fn start_stopwatch(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.timer.start_stopwatch()?;
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Started { duration: None });
+        Ok(())
+    }
+    
     /// Pause the currently running timer
     pub fn pause_timer(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let result = self.timer.pause()?;
-        self.mark_interaction();
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Paused);
         Ok(result)
     }
     
     /// Resume a paused timer
     pub fn resume_timer(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let result = self.timer.resume()?;
-        self.mark_interaction();
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Resumed);
         Ok(result)
     }
     
-    /// Reset the timer to stopped state
+    /// Reset the timer to stopped state, ending any Pomodoro session or sequence
     pub fn reset_timer(&mut self) {
         self.timer.reset();
+        self.pomodoro = None;
+        self.sequence = None;
+        self.recent_timer = None;
         self.notification_triggered = false;
+        self.mark_timer_changed();
+        self.emit(TimerSource::Main, TimerEventKind::Reset);
+    }
+    
+    /// Record a lap on the running timer
+    pub fn record_lap(&mut self) -> Result<Lap, Box<dyn std::error::Error>> {
+        let lap = self.timer.record_lap()?;
         self.mark_interaction();
+        Ok(lap)
+    }
+    
+    /// Add a named timer and make it the most recent one
+    pub fn add_named_timer(&mut self, name: &str, preset: Duration) -> Result<TimerId, Box<dyn std::error::Error>> {
+        Timer::validate_duration(preset)?;
+        
+        let id = self.timers.add(name, preset);
+        self.recent_timer = Some(id);
+        self.mark_timer_changed();
+        Ok(id)
+    }
+    
+    /// Remove a named timer
+    pub fn remove_named_timer(&mut self, id: TimerId) -> Result<(), Box<dyn std::error::Error>> {
+        self.timers.remove(id).ok_or("No timer with that ID")?;
+        if self.focused_timer == Some(id) {
+            self.focused_timer = None;
+        }
+        if self.recent_timer == Some(id) {
+            self.recent_timer = None;
+        }
+        self.mark_timer_changed();
+        Ok(())
+    }
+    
+    /// Start a named timer from its preset duration
+    pub fn start_named_timer(&mut self, id: TimerId) -> Result<(), Box<dyn std::error::Error>> {
+        let named = self.named_timer_mut(id)?;
+        let preset = named.preset;
+        named.timer.start(preset)?;
+        self.emit(TimerSource::Named(id), TimerEventKind::Started { duration: Some(preset) });
+        Ok(())
+    }
+    
+    /// Pause a running named timer
+    pub fn pause_named_timer(&mut self, id: TimerId) -> Result<(), Box<dyn std::error::Error>> {
+        self.named_timer_mut(id)?.timer.pause()?;
+        self.emit(TimerSource::Named(id), TimerEventKind::Paused);
+        Ok(())
+    }
+    
+    /// Resume a paused named timer
+    pub fn resume_named_timer(&mut self, id: TimerId) -> Result<(), Box<dyn std::error::Error>> {
+        self.named_timer_mut(id)?.timer.resume()?;
+        self.emit(TimerSource::Named(id), TimerEventKind::Resumed);
+        Ok(())
+    }
+    
+    /// Reset a named timer to stopped state
+    pub fn reset_named_timer(&mut self, id: TimerId) -> Result<(), Box<dyn std::error::Error>> {
+        self.named_timer_mut(id)?.timer.reset();
+        self.emit(TimerSource::Named(id), TimerEventKind::Reset);
+        Ok(())
+    }
+    
+    /// Start, pause or resume a named timer depending on its state
+    pub fn toggle_named_timer(&mut self, id: TimerId) -> Result<(), Box<dyn std::error::Error>> {
+        let named = self.named_timer_mut(id)?;
+        let was_idle = matches!(
+            named.timer.state(),
+            TimerState::Stopped | TimerState::Finished | TimerState::Overtime { .. }
+        );
+        named.toggle()?;
+        
+        let kind = if was_idle {
+            TimerEventKind::Started { duration: Some(named.preset) }
+        } else if named.timer.state().is_paused() {
+            TimerEventKind::Paused
+        } else {
+            TimerEventKind::Resumed
+        };
+        self.emit(TimerSource::Named(id), kind);
+        Ok(())
+    }
+    
+    /// Add time to a running or paused named timer
+    pub fn add_named_time(&mut self, id: TimerId, amount: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        let named = self.named_timer_mut(id)?;
+        named.timer.add_time(amount)?;
+        let remaining = named.timer.remaining_time().unwrap_or_default();
+        self.emit(TimerSource::Named(id), TimerEventKind::TimeAdjusted { remaining });
+        Ok(())
+    }
+    
+    /// Subtract time from a running or paused named timer, finishing it at zero
+    pub fn subtract_named_time(&mut self, id: TimerId, amount: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        let named = self.named_timer_mut(id)?;
+        named.timer.subtract_time(amount)?;
+        let remaining = named.timer.remaining_time().unwrap_or_default();
+        let finished = named.timer.is_finished();
+        self.emit(TimerSource::Named(id), TimerEventKind::TimeAdjusted { remaining });
+        
+        if finished {
+            self.emit(TimerSource::Named(id), TimerEventKind::Finished);
+            self.start_ringing(TimerSource::Named(id));
+        }
+        Ok(())
+    }
+    
+    /// Add time to the hotkey target, or to the main timer if there is none
+    pub fn add_time_to_target(&mut self, amount: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        match self.hotkey_target() {
+            Some(id) => self.add_named_time(id, amount),
+            None => self.add_time(amount),
+        }
+    }
+    
+    /// Subtract time from the hotkey target, or from the main timer if there is none
+    pub fn subtract_time_from_target(&mut self, amount: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        match self.hotkey_target() {
+            Some(id) => self.subtract_named_time(id, amount),
+            None => self.subtract_time(amount),
+        }
+    }
+    
+    /// Focus a named timer (or the main timer with None) for hotkey control
+    pub fn focus_timer(&mut self, id: Option<TimerId>) {
+        self.focused_timer = id.filter(|id| self.timers.get(*id).is_some());
+        self.mark_interaction();
+    }
+    
+    /// Named timer that hotkeys act on: the focused one, else the most recent one
+    pub fn hotkey_target(&self) -> Option<TimerId> {
+        self.focused_timer
+            .or(self.recent_timer)
+            .filter(|id| self.timers.get(*id).is_some())
+    }
+    
+    /// Look up a named timer for a control action and record it as most recent
+    fn named_timer_mut(&mut self, id: TimerId) -> Result<&mut NamedTimer, Box<dyn std::error::Error>> {
+        if self.timers.get(id).is_none() {
+            return Err("No timer with that ID".into());
+        }
+        
+        self.recent_timer = Some(id);
+        self.mark_timer_changed();
+        self.timers.get_mut(id).ok_or_else(|| "No timer with that ID".into())
     }
     
     /// Get current timer state
@@ -84,24 +457,413 @@ impl AppState {
         self.timer.remaining_time()
     }
     
+    /// Format a duration with the configured display template
+    ///
+    /// Percent, target and phase tokens are filled in from the main timer.
+    pub fn format_time(&self, duration: Duration) -> String {
+        let progress = match (self.timer.state(), self.timer.remaining_time()) {
+            (TimerState::Running { .. } | TimerState::Paused { .. }, Some(remaining))
+                if !self.timer.original_duration.is_zero() =>
+            {
+                let total = self.timer.original_duration.as_secs_f32();
+                Some(1.0 - remaining.as_secs_f32() / total)
+            }
+            _ => None,
+        };
+        let target = match self.timer.state() {
+            TimerState::Running { deadline, .. } => Some(self.timer.alarm_target.unwrap_or(*deadline)),
+            _ => self.timer.alarm_target,
+        };
+        let phase = self.pomodoro.as_ref()
+            .map(|session| session.phase.label().to_string())
+            .or_else(|| self.sequence.as_ref().map(|run| run.current_step().label.clone()));
+        
+        self.config.display.parsed_time_format().format(&FormatContext {
+            duration,
+            progress,
+            target,
+            phase: phase.as_deref(),
+        })
+    }
+    
+    /// Get elapsed time if a stopwatch or countdown is in progress
+    pub fn elapsed_time(&self) -> Option<Duration> {
+        self.timer.elapsed_time()
+    }
+    
+    /// Get time spent past zero if the timer is in overtime
+    pub fn overtime(&self) -> Option<Duration> {
+        self.timer.overtime()
+    }
+    
+    /// Dismiss an overtime display, returning the timer to stopped state
+    pub fn dismiss_overtime(&mut self) {
+        if matches!(self.timer_state(), TimerState::Overtime { .. }) {
+            self.reset_timer();
+        }
+    }
+    
     /// Check if timer has finished
     pub fn is_timer_finished(&self) -> bool {
         self.timer.is_finished()
     }
     
-    /// Update timer state and return true if state changed
+    /// Update timer state from the shared clock and return true if state changed
     pub fn tick_timer(&mut self) -> bool {
         let state_changed = self.timer.tick();
         
         // Check for timer completion
         if state_changed && self.timer.is_finished() {
+            self.handle_timer_finished();
+        }
+        
+        // Named timers finish independently of the main timer; subscribers hear about it through `Finished`
+        let finished_named = self.timers.tick();
+        for id in &finished_named {
+            self.emit(TimerSource::Named(*id), TimerEventKind::Finished);
+            self.start_ringing(TimerSource::Named(*id));
+        }
+        self.check_warnings();
+        self.tick_ringing();
+        
+        let changed = state_changed || !finished_named.is_empty();
+        if changed {
+            self.session_dirty = true;
+        }
+        changed
+    }
+    
+    /// Capture the main timer, its Pomodoro/sequence progress and all named timers
+    pub fn session_snapshot(&self) -> SessionSnapshot {
+        let label = self.pomodoro.as_ref()
+            .map(|session| session.display_label())
+            .or_else(|| self.sequence.as_ref().map(|run| run.display_label()));
+        
+        SessionSnapshot {
+            saved_at_ms: to_epoch_ms(self.clock.wall_now()),
+            timer: self.timer.snapshot(),
+            label,
+            pomodoro: self.pomodoro.clone(),
+            sequence: self.sequence.clone(),
+            named_timers: self.timers.iter().map(|named| NamedTimerSnapshot {
+                name: named.name.clone(),
+                preset_ms: to_ms(named.preset),
+                timer: named.timer.snapshot(),
+            }).collect(),
+        }
+    }
+    
+    /// Restore a session saved by a previous run
+    ///
+    /// Countdowns whose deadline passed while the app was closed finish
+    /// immediately and raise the usual notification.
+    pub fn restore_session(&mut self, snapshot: &SessionSnapshot) {
+        self.timers = TimerCollection::new(self.clock.clone());
+        self.focused_timer = None;
+        self.recent_timer = None;
+        for named in &snapshot.named_timers {
+            let id = self.timers.add(&named.name, Duration::from_millis(named.preset_ms));
+            if let Some(restored) = self.timers.get_mut(id) {
+                restored.timer.restore(&named.timer);
+                if restored.timer.finished_while_suspended {
+                    self.emit(TimerSource::Named(id), TimerEventKind::Finished);
+                    self.start_ringing(TimerSource::Named(id));
+                }
+            }
+        }
+        
+        self.pomodoro = snapshot.pomodoro.clone();
+        self.sequence = snapshot.sequence.clone();
+        self.timer.restore(&snapshot.timer);
+        self.sync_timer_settings();
+        if self.timer.finished_while_suspended {
+            self.handle_timer_finished();
+        }
+        self.session_dirty = true;
+    }
+    
+    /// Add time to the running or paused countdown
+    pub fn add_time(&mut self, amount: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        self.timer.add_time(amount)?;
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit_time_adjusted();
+        Ok(())
+    }
+    
+    /// Subtract time from the running or paused countdown
+    pub fn subtract_time(&mut self, amount: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        self.timer.subtract_time(amount)?;
+        self.recent_timer = None;
+        self.mark_timer_changed();
+        self.emit_time_adjusted();
+        
+        // Subtracting down to zero finishes the countdown just like a tick would
+        if self.timer.is_finished() {
+            self.handle_timer_finished();
+        }
+        self.check_warnings();
+        Ok(())
+    }
+    
+    /// Notify and chain into the next Pomodoro phase or sequence step
+    ///
+    /// Subscribers see `PhaseChanged` when the cycle continues and `Finished`
+    /// only when the timer stays finished.
+    fn handle_timer_finished(&mut self) {
+        let chained = self.pomodoro.is_some() || self.sequence.is_some();
+        
+        // Chain straight into the next Pomodoro phase
+        if let Some(session) = self.pomodoro.as_mut() {
+            session.advance();
+            let next_duration = session.current_duration();
+            if self.timer.start(next_duration).is_err() {
+                self.pomodoro = None;
+            }
+        }
+        
+        // Advance the sequence; the last step leaves the timer finished
+        if let Some(run) = self.sequence.as_mut() {
+            match run.advance().map(|step| step.duration()) {
+                Some(duration) => {
+                    if self.timer.start(duration).is_err() {
+                        self.sequence = None;
+                    }
+                }
+                None => self.sequence = None,
+            }
+        }
+        
+        if chained && !self.timer.is_finished() {
+            self.emit_step_change(true);
+        } else {
             self.notification_triggered = true;
+            self.emit(TimerSource::Main, TimerEventKind::Finished);
+            self.start_ringing(TimerSource::Main);
+        }
+    }
+    
+    /// Keep a finished timer's alarm ringing if configured to repeat
+    fn start_ringing(&mut self, source: TimerSource) {
+        if self.config.notifications.repeat_until_acknowledged {
+            self.ringing.retain(|ringing| ringing.source != source);
+            self.ringing.push(Ringing::new(source, self.clock.now()));
+        }
+    }
+    
+    /// Repeat each ringing cue when due, or stop it after the maximum ring duration
+    fn tick_ringing(&mut self) {
+        let notifications = &self.config.notifications;
+        let interval = Self::configured_duration(&notifications.ring_interval, RING_INTERVAL);
+        let max_duration = Self::configured_duration(&notifications.max_ring_duration, MAX_RING_DURATION);
+        let now = self.clock.now();
+        
+        let mut events = Vec::new();
+        self.ringing.retain_mut(|ringing| match ringing.step(now, interval, max_duration) {
+            RingStep::Quiet => true,
+            RingStep::Repeat => {
+                events.push((ringing.source, TimerEventKind::Ringing { repeats: ringing.repeats }));
+                true
+            }
+            RingStep::TimedOut => {
+                events.push((ringing.source, TimerEventKind::RingTimedOut));
+                false
+            }
+        });
+        for (source, kind) in events {
+            self.emit(source, kind);
+        }
+    }
+    
+    /// Check if any finished alarm is ringing
+    pub fn is_ringing(&self) -> bool {
+        !self.ringing.is_empty()
+    }
+    
+    /// Check if the given timer's alarm is ringing
+    pub fn is_ringing_for(&self, source: TimerSource) -> bool {
+        self.ringing.iter().any(|ringing| ringing.source == source)
+    }
+    
+    /// Timer whose alarm has been ringing longest, answered first by snooze and acknowledge
+    fn oldest_ringing(&self) -> Option<TimerSource> {
+        self.ringing.first().map(|ringing| ringing.source)
+    }
+    
+    /// Check if any timer is counting or an alarm is ringing, so the UI keeps ticking
+    pub fn needs_ticking(&self) -> bool {
+        !matches!(self.timer.state(), TimerState::Stopped)
+            || self.is_ringing()
+            || self.timers.iter().any(|named| {
+                let state = named.timer.state();
+                state.is_active() || matches!(state, TimerState::Overtime { .. })
+            })
+    }
+    
+    /// Length of the countdown restarted by a snooze, from configuration
+    pub fn snooze_duration(&self) -> Duration {
+        Self::configured_duration(&self.config.notifications.snooze_duration, SNOOZE_DURATION)
+    }
+    
+    /// Stop the longest-ringing alarm; see `acknowledge_alarm_for`
+    pub fn acknowledge_alarm(&mut self) {
+        if let Some(source) = self.oldest_ringing() {
+            self.acknowledge_alarm_for(source);
+        }
+    }
+    
+    /// Stop a timer's ringing alarm; a finished countdown returns to stopped
+    ///
+    /// Overtime keeps counting after the alarm is acknowledged until it is dismissed.
+    pub fn acknowledge_alarm_for(&mut self, source: TimerSource) {
+        if !self.is_ringing_for(source) {
+            return;
+        }
+        self.ringing.retain(|ringing| ringing.source != source);
+        self.emit(source, TimerEventKind::Acknowledged);
+        match source {
+            TimerSource::Main => {
+                if matches!(self.timer_state(), TimerState::Finished) {
+                    self.reset_timer();
+                }
+            }
+            TimerSource::Named(id) => {
+                let finished = self.timers.get(id).is_some_and(|named| named.timer.is_finished());
+                if finished {
+                    let _ = self.reset_named_timer(id);
+                }
+            }
+        }
+    }
+    
+    /// Snooze the longest-ringing alarm; see `snooze_alarm_for`
+    pub fn snooze_alarm(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let source = self.oldest_ringing()
+            .ok_or_else(|| TimerError::InvalidState("No alarm is ringing".to_string()))?;
+        self.snooze_alarm_for(source)
+    }
+    
+    /// Stop a timer's ringing alarm and count down the snooze duration on the same timer
+    pub fn snooze_alarm_for(&mut self, source: TimerSource) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.is_ringing_for(source) {
+            return Err(TimerError::InvalidState("No alarm is ringing".to_string()).into());
+        }
+        self.ringing.retain(|ringing| ringing.source != source);
+        let duration = self.snooze_duration();
+        self.emit(source, TimerEventKind::Snoozed { duration });
+        
+        match source {
+            TimerSource::Main => {
+                self.timer.reset();
+                self.start_timer(duration)
+            }
+            TimerSource::Named(id) => {
+                let named = self.named_timer_mut(id)?;
+                named.timer.reset();
+                named.timer.start(duration)?;
+                self.mark_timer_changed();
+                self.emit(TimerSource::Named(id), TimerEventKind::Started { duration: Some(duration) });
+                Ok(())
+            }
+        }
+    }
+    
+    /// Register a subscriber for timer events
+    pub fn subscribe(&mut self, subscriber: Box<dyn TimerNotifications + Send>) -> SubscriptionId {
+        self.events.subscribe(subscriber)
+    }
+    
+    /// Remove an event subscriber
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        self.events.unsubscribe(id)
+    }
+    
+    /// Publish an event, first reporting any change of the main timer's state
+    fn emit(&mut self, source: TimerSource, kind: TimerEventKind) {
+        // A new countdown, phase or reset restarts warnings and silences that timer's alarm
+        if matches!(kind, TimerEventKind::Started { .. } | TimerEventKind::PhaseChanged { .. } | TimerEventKind::Reset) {
+            if source == TimerSource::Main {
+                self.arm_warnings();
+                self.notification_triggered = false;
+            }
+            if self.is_ringing_for(source) {
+                self.ringing.retain(|ringing| ringing.source != source);
+                let name = self.source_name(source);
+                self.events.publish(&TimerEvent { source, kind: TimerEventKind::Acknowledged, name });
+            }
         }
         
-        state_changed
+        let current = self.timer.state().clone();
+        if std::mem::discriminant(&current) != std::mem::discriminant(&self.published_state) {
+            self.events.publish_state_change(&self.published_state, &current);
+            self.published_state = current;
+        }
+        let name = self.source_name(source);
+        self.events.publish(&TimerEvent { source, kind, name });
+    }
+    
+    /// Name shown in notifications for a named timer's events
+    fn source_name(&self, source: TimerSource) -> Option<String> {
+        match source {
+            TimerSource::Main => None,
+            TimerSource::Named(id) => self.timers.get(id).map(|named| named.name.clone()),
+        }
     }
     
-    /// Check if notification was triggered
+    /// Publish the Pomodoro phase or sequence step that just began
+    fn emit_step_change(&mut self, automatic: bool) {
+        let label = self.pomodoro.as_ref()
+            .map(|session| session.display_label())
+            .or_else(|| self.sequence.as_ref().map(|run| run.display_label()));
+        let kind = match label {
+            Some(label) => TimerEventKind::PhaseChanged { label, automatic },
+            None => TimerEventKind::Reset, // Skipped past the end of a sequence
+        };
+        self.emit(TimerSource::Main, kind);
+    }
+    
+    /// Publish a warning for the most urgent threshold crossed since the last check
+    fn check_warnings(&mut self) {
+        if !matches!(self.timer.state(), TimerState::Running { .. }) {
+            return;
+        }
+        let remaining = self.timer.remaining_time().unwrap_or_default();
+        if let Some(warning) = self.warnings.check(remaining).cloned() {
+            let kind = TimerEventKind::Warning {
+                remaining: warning.remaining,
+                actions: warning.actions,
+            };
+            self.emit(TimerSource::Main, kind);
+        }
+    }
+    
+    /// Arm the warning thresholds for the current countdown, or disarm them if there is none
+    fn arm_warnings(&mut self) {
+        match self.timer.state() {
+            TimerState::Running { .. } | TimerState::Paused { .. } => {
+                let remaining = self.timer.remaining_time().unwrap_or_default();
+                self.warnings.arm(remaining);
+            }
+            _ => self.warnings.disarm(),
+        }
+    }
+    
+    /// Warning threshold the main countdown is currently within, for visual cues
+    pub fn active_warning(&self) -> Option<&Warning> {
+        match self.timer.state() {
+            TimerState::Running { .. } | TimerState::Paused { .. } => {
+                self.warnings.active(self.timer.remaining_time()?)
+            }
+            _ => None,
+        }
+    }
+    
+    fn emit_time_adjusted(&mut self) {
+        let remaining = self.timer.remaining_time().unwrap_or_default();
+        self.emit(TimerSource::Main, TimerEventKind::TimeAdjusted { remaining });
+    }
+    
+    /// Check if the main timer finished and has not been restarted or reset since
     pub fn was_notification_triggered(&self) -> bool {
         self.notification_triggered
     }
@@ -235,6 +997,12 @@ impl AppState {
                 self.set_window_visible(!self.is_window_visible());
             }
             keys if Some(keys.to_string()) == self.config.hotkeys.start_stop => {
+                // Focused or most recently used named timer takes precedence
+                if let Some(id) = self.hotkey_target() {
+                    let _ = self.toggle_named_timer(id);
+                    return;
+                }
+                
                 match self.timer_state() {
                     TimerState::Stopped | TimerState::Finished => {
                         // Use last timer duration or default
@@ -243,18 +1011,47 @@ impl AppState {
                         } else {
                             self.timer.original_duration
                         };
-                        let _ = self.start_timer(duration);
+                        let _ = self.start_default(duration);
                     }
-                    TimerState::Running { .. } => {
+                    TimerState::Running { .. } | TimerState::StopwatchRunning { .. } => {
                         let _ = self.pause_timer();
                     }
-                    TimerState::Paused { .. } => {
+                    TimerState::Paused { .. } | TimerState::StopwatchPaused { .. } => {
                         let _ = self.resume_timer();
                     }
+                    TimerState::Overtime { .. } => {
+                        self.dismiss_overtime();
+                    }
                 }
             }
             keys if Some(keys.to_string()) == self.config.hotkeys.reset => {
-                self.reset_timer();
+                match self.hotkey_target() {
+                    Some(id) => {
+                        let _ = self.reset_named_timer(id);
+                    }
+                    None => self.reset_timer(),
+                }
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.record_lap => {
+                let _ = self.record_lap();
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.add_time => {
+                let _ = self.add_time_to_target(self.time_adjust_step());
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.subtract_time => {
+                let _ = self.subtract_time_from_target(self.time_adjust_step());
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.skip_step => {
+                let _ = self.skip_step();
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.previous_step => {
+                let _ = self.previous_step();
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.acknowledge => {
+                self.acknowledge_alarm();
+            }
+            keys if Some(keys.to_string()) == self.config.hotkeys.snooze => {
+                let _ = self.snooze_alarm();
             }
             _ => {
                 // Unknown hotkey
@@ -276,18 +1073,25 @@ impl AppState {
         let mut controls = Vec::new();
         
         match self.timer_state() {
+            TimerState::Finished if self.is_ringing_for(TimerSource::Main) => {
+                controls.push("snooze".to_string());
+                controls.push("acknowledge".to_string());
+            }
             TimerState::Stopped | TimerState::Finished => {
                 controls.push("start".to_string());
                 controls.push("reset".to_string());
             }
-            TimerState::Running { .. } => {
+            TimerState::Running { .. } | TimerState::StopwatchRunning { .. } => {
                 controls.push("pause".to_string());
                 controls.push("reset".to_string());
             }
-            TimerState::Paused { .. } => {
+            TimerState::Paused { .. } | TimerState::StopwatchPaused { .. } => {
                 controls.push("resume".to_string());
                 controls.push("reset".to_string());
             }
+            TimerState::Overtime { .. } => {
+                controls.push("dismiss".to_string());
+            }
         }
         
         controls
@@ -301,9 +1105,18 @@ impl AppState {
     /// Apply new configuration
     pub fn apply_configuration(&mut self, config: Configuration) {
         self.config = config;
+        self.sync_timer_settings();
+        self.hotkey_manager.register(&self.config.hotkeys);
         self.mark_config_dirty();
     }
     
+    /// Push timer-related behavior settings down to the main timer
+    fn sync_timer_settings(&mut self) {
+        self.timer.overtime_enabled = self.config.behavior.overtime_enabled;
+        self.warnings = WarningTracker::from_config(&self.config.notifications.warnings);
+        self.arm_warnings();
+    }
+    
     /// Check if configuration needs saving
     pub fn is_config_dirty(&self) -> bool {
         self.config_dirty
@@ -319,13 +1132,123 @@ impl AppState {
         !self.config_dirty
     }
     
-    /// Save configuration (placeholder for actual implementation)
+    /// Replace the configuration with the one stored by the config manager
+    pub fn load_configuration(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(manager) = &self.config_manager else {
+            return Ok(());
+        };
+        // Upgrades or repairs the file, even when it is only the user layer
+        let (config, report) = manager.load_with_report()?;
+        report.log();
+        self.config_notices = report.notices();
+        self.config = match self.config_resolver.as_ref().map(|resolver| resolver.resolve()) {
+            Some(Ok(layered)) => {
+                for warning in &layered.warnings {
+                    eprintln!("Configuration: {}", warning);
+                }
+                let config = layered.config.clone();
+                self.config_layers = Some(layered);
+                config
+            }
+            Some(Err(e)) => {
+                // Without layers a save cannot tell the user's settings apart, so it is refused
+                eprintln!("Cannot apply configuration layers, using the user file only: {}", e);
+                self.config_notices.push(format!("Configuration layers not applied, using the user file only: {}", e));
+                self.config_layers = None;
+                config
+            }
+            None => config,
+        };
+        self.sync_timer_settings();
+        self.hotkey_manager.register(&self.config.hotkeys);
+        self.mark_config_saved();
+        // Loading may have upgraded or repaired the file
+        self.acknowledge_config_file();
+        Ok(())
+    }
+    
+    /// Persist the configuration through the config manager, if one is attached
+    ///
+    /// With layered configuration only the user's own changes are written; see
+    /// `LayeredConfig::user_values`. Nothing is written while the layers do not
+    /// resolve, since the configuration in use may not be the user's own.
     pub fn save_configuration(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        // This will be implemented by the config manager service
+        if let Some(manager) = &self.config_manager {
+            match (&self.config_resolver, &self.config_layers) {
+                (Some(_), None) => {
+                    return Err("Configuration layers did not resolve; not saving over the user file".into());
+                }
+                (Some(resolver), Some(layers)) => {
+                    let errors = self.config.validate_fields();
+                    if !errors.is_empty() {
+                        return Err(Box::new(ConfigError::ValidationFailed(errors)));
+                    }
+                    let values = layers.user_values(&self.config)?;
+                    manager.save_values(&values, self.config.behavior.config_backups as usize)?;
+                    // The next save compares against what the user file now holds
+                    self.config_layers = Some(resolver.resolve()?);
+                }
+                _ => manager.save(&self.config)?,
+            }
+            self.acknowledge_config_file();
+        }
         self.mark_config_saved();
         Ok(())
     }
     
+    /// Hotkeys as last registered with the hotkey manager
+    pub fn registered_hotkeys(&self) -> Option<&HotkeyConfig> {
+        self.hotkey_manager.registered()
+    }
+    
+    /// Where each effective setting came from, when the configuration is layered
+    pub fn config_layers(&self) -> Option<&LayeredConfig> {
+        self.config_layers.as_ref()
+    }
+    
+    /// Keep the watcher from reporting our own writes as outside edits
+    fn acknowledge_config_file(&mut self) {
+        if let Some(watcher) = &mut self.config_watcher {
+            watcher.acknowledge();
+        }
+    }
+    
+    /// Apply edits made to the configuration file by other programs
+    ///
+    /// Returns true when a new configuration took effect. An edit that does not
+    /// load or validate is rejected: the current configuration stays active and
+    /// the reason is kept in `config_error`. With layered configuration, an edit
+    /// of the user file resolves every layer again.
+    pub fn reload_changed_configuration(&mut self) -> bool {
+        let now = self.clock.now();
+        if !self.config_watcher.as_mut().is_some_and(|watcher| watcher.changed(now)) {
+            return false;
+        }
+        let result = match self.config_resolver.as_ref().map(|resolver| resolver.resolve()) {
+            Some(Ok(layered)) => {
+                let config = layered.config.clone();
+                self.config_layers = Some(layered);
+                Ok(config)
+            }
+            Some(Err(e)) => Err(e),
+            None => self.config_manager.as_ref().map_or(Err(ConfigError::FileNotFound), |manager| manager.load_strict()),
+        };
+        match result {
+            Ok(config) => {
+                self.apply_configuration(config);
+                // Already on disk; saving it again would reformat the user's file
+                self.mark_config_saved();
+                self.config_error = None;
+                true
+            }
+            Err(e) => {
+                eprintln!("Ignoring configuration edit: {}", e);
+                self.config_error = Some(e.to_string());
+                false
+            }
+        }
+    }
+    
     /// Get monitors information
     pub fn get_monitors(&self) -> Vec<crate::models::display::MonitorInfo> {
         self.display_context.monitors.clone()
@@ -346,7 +1269,13 @@ impl AppState {
     
     /// Mark user interaction timestamp
     fn mark_interaction(&mut self) {
-        self.ui_state.last_interaction = Some(std::time::Instant::now());
+        self.ui_state.last_interaction = Some(self.clock.now());
+    }
+    
+    /// Record a user action that changed a timer, so the session gets persisted
+    fn mark_timer_changed(&mut self) {
+        self.mark_interaction();
+        self.session_dirty = true;
     }
     
     /// Mark configuration as needing save
@@ -364,6 +1293,11 @@ impl Default for AppState {
 impl UiState {
     /// Create new UI state with default values
     pub fn new() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+    
+    /// Create new UI state measuring inactivity with the given clock
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             is_visible: true,
             is_hovered: false,
@@ -373,6 +1307,7 @@ impl UiState {
             settings_window_open: false,
             controls_visible: false,
             last_interaction: None,
+            clock,
         }
     }
     
@@ -383,7 +1318,7 @@ impl UiState {
     
     /// Get time since last interaction
     pub fn time_since_interaction(&self) -> Option<Duration> {
-        self.last_interaction.map(|instant| instant.elapsed())
+        self.last_interaction.map(|instant| self.clock.elapsed_since(instant))
     }
     
     /// Check if UI should auto-hide based on inactivity
@@ -413,6 +1348,7 @@ pub struct MemoryInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::clock::{Clock, ManualClock};
     
     #[test]
     fn test_app_state_creation() {
@@ -513,7 +1449,166 @@ mod tests {
         assert!(result.is_ok());
         assert!(!app.is_config_dirty());
     }
-    
+
+    #[test]
+    fn test_configuration_persists_through_manager() {
+        let dir = std::env::temp_dir().join(format!("ghost_timer_app_config_{}", std::process::id()));
+        let path = dir.join(crate::services::config_manager::CONFIG_FILE_NAME);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // Nothing on disk yet: defaults, nothing to save
+        let mut app = AppState::with_config_manager(ConfigManagerImpl::with_path(&path));
+        assert_eq!(app.config.display.position, Configuration::default().display.position);
+        assert!(!app.is_config_dirty());
+
+        app.set_window_position(320, 240).unwrap();
+        assert!(app.is_config_dirty());
+        app.save_configuration().unwrap();
+        assert!(app.is_config_saved());
+
+        // The next start picks up the remembered position
+        let restored = AppState::with_config_manager(ConfigManagerImpl::with_path(&path));
+        assert_eq!(restored.window_position(), (320, 240));
+        assert!(!restored.is_config_dirty());
+        assert!(restored.config_notices.is_empty());
+
+        // A damaged value is reset and reported, the rest is kept
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, text.replace("\"transparency\": 0.3", "\"transparency\": \"clear\"")).unwrap();
+        let repaired = AppState::with_config_manager(ConfigManagerImpl::with_path(&path));
+        assert_eq!(repaired.window_position(), (320, 240));
+        assert!(repaired.config_notices[0].starts_with("display.transparency"));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_outside_config_edits_are_applied() {
+        let dir = std::env::temp_dir().join(format!("ghost_timer_app_reload_{}", std::process::id()));
+        let path = dir.join(crate::services::config_manager::CONFIG_FILE_NAME);
+        let _ = std::fs::remove_dir_all(&dir);
+        let manager = ConfigManagerImpl::with_path(&path);
+        manager.save(&Configuration::default()).unwrap();
+
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        app.config_manager = Some(manager.clone());
+        app.config_watcher = Some(manager.watcher());
+        app.load_configuration().unwrap();
+        assert!(!app.reload_changed_configuration());
+
+        // Saving from the app is not picked up as an edit
+        app.set_window_position(100, 100).unwrap();
+        app.save_configuration().unwrap();
+        clock.advance(Duration::from_secs(2));
+        assert!(!app.reload_changed_configuration());
+
+        let mut edited = app.config.clone();
+        edited.display.position = (400, 300);
+        edited.hotkeys.start_stop = Some("Ctrl+Alt+P".to_string());
+        edited.behavior.overtime_enabled = true;
+        std::fs::write(&path, serde_json::to_string_pretty(&edited).unwrap()).unwrap();
+        clock.advance(Duration::from_secs(2));
+        assert!(app.reload_changed_configuration());
+        assert_eq!(app.window_position(), (400, 300));
+        assert!(app.timer.overtime_enabled);
+        assert!(!app.is_config_dirty());
+
+        // New hotkeys are registered and take effect immediately
+        let registered = app.registered_hotkeys().unwrap();
+        assert_eq!(registered.start_stop.as_deref(), Some("Ctrl+Alt+P"));
+        app.handle_hotkey("Ctrl+Alt+P");
+        assert!(matches!(app.timer_state(), TimerState::Running { .. }));
+
+        // An invalid edit leaves the running configuration alone
+        edited.display.transparency = 3.0;
+        std::fs::write(&path, serde_json::to_string_pretty(&edited).unwrap()).unwrap();
+        clock.advance(Duration::from_secs(2));
+        assert!(!app.reload_changed_configuration());
+        assert!(app.config_error.as_ref().unwrap().contains("display.transparency"));
+        assert_eq!(app.window_position(), (400, 300));
+        assert_ne!(app.config.display.transparency, 3.0);
+        assert_eq!(app.registered_hotkeys().unwrap().start_stop.as_deref(), Some("Ctrl+Alt+P"));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_layered_configuration_saves_only_user_changes() {
+        use crate::services::config_layers::ConfigLayer;
+
+        let dir = std::env::temp_dir().join(format!("ghost_timer_app_layers_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let system = dir.join("system.toml");
+        let user = dir.join(crate::services::config_manager::CONFIG_FILE_NAME);
+        std::fs::write(&system, "[display]\ntransparency = 0.5\n\n[behavior]\novertime_enabled = true\n").unwrap();
+        let resolver = ConfigResolver::new()
+            .with_file(ConfigLayer::System, &system)
+            .with_file(ConfigLayer::User, &user)
+            .with_overrides(&["display.hover_transparency=0.9".to_string()])
+            .unwrap();
+
+        let mut app = AppState::with_layered_config(ConfigManagerImpl::with_path(&user), resolver);
+        assert_eq!(app.config.display.transparency, 0.5);
+        assert!(app.timer.overtime_enabled);
+        let layers = app.config_layers().unwrap();
+        assert_eq!(layers.source("display.transparency"), Some(ConfigLayer::System));
+        assert_eq!(layers.source("display.hover_transparency"), Some(ConfigLayer::CommandLine));
+
+        app.set_window_position(320, 240).unwrap();
+        app.save_configuration().unwrap();
+        assert_eq!(app.config_layers().unwrap().source("display.position"), Some(ConfigLayer::User));
+
+        // The user file holds the moved window, not the baseline or the override
+        let saved = std::fs::read_to_string(&user).unwrap();
+        assert!(saved.contains("position"));
+        assert!(!saved.contains("transparency"));
+        assert!(!saved.contains("overtime_enabled"));
+
+        // A later baseline change still reaches the user
+        std::fs::write(&system, "[display]\ntransparency = 0.6\n").unwrap();
+        let resolver = ConfigResolver::new()
+            .with_file(ConfigLayer::System, &system)
+            .with_file(ConfigLayer::User, &user);
+        let restarted = AppState::with_layered_config(ConfigManagerImpl::with_path(&user), resolver);
+        assert_eq!(restarted.config.display.transparency, 0.6);
+        assert_eq!(restarted.window_position(), (320, 240));
+        assert!(restarted.config_notices.is_empty());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_unresolved_layers_keep_the_user_file() {
+        use crate::services::config_layers::ConfigLayer;
+
+        let dir = std::env::temp_dir().join(format!("ghost_timer_app_bad_layers_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let user = dir.join(crate::services::config_manager::CONFIG_FILE_NAME);
+        let original = r#"{ "version": "2.1", "display": { "transparency": 0.33 }, "pomodoro": { "work_minutes": 50 } }"#;
+        std::fs::write(&user, original).unwrap();
+        let resolver = ConfigResolver::new()
+            .with_file(ConfigLayer::User, &user)
+            .with_overrides(&["display.hover_transparency=2".to_string()])
+            .unwrap();
+
+        // The bad override is reported and the user's own settings stay in use
+        let mut app = AppState::with_layered_config(ConfigManagerImpl::with_path(&user), resolver);
+        assert!(app.config_layers().is_none());
+        assert_eq!(app.config.display.transparency, 0.33);
+        assert_eq!(app.config.pomodoro.work_minutes, 50);
+        assert!(app.config_notices.iter().any(|notice| notice.contains("layers not applied")));
+
+        // Moving the window must not write the merged settings over the user file
+        app.set_window_position(320, 240).unwrap();
+        assert!(app.save_configuration().is_err());
+        assert_eq!(std::fs::read_to_string(&user).unwrap(), original);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn test_hotkey_handling() {
         let mut app = AppState::new();
@@ -535,6 +1630,10 @@ mod tests {
         app.handle_hotkey("Ctrl+Alt+S"); // Should resume
         assert!(matches!(app.timer_state(), TimerState::Running { .. }));
         
+        // Test lap recording
+        app.handle_hotkey("Ctrl+Alt+L");
+        assert_eq!(app.timer.laps.len(), 1);
+        
         // Test reset
         app.handle_hotkey("Ctrl+Alt+R");
         assert!(matches!(app.timer_state(), TimerState::Stopped));
@@ -577,4 +1676,556 @@ mod tests {
         interactive_state.is_hovered = true;
         assert!(!interactive_state.should_auto_hide(Duration::from_secs(5)));
     }
+    
+    #[test]
+    fn test_stopwatch_operations() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        
+        app.start_stopwatch().unwrap();
+        assert!(matches!(app.timer_state(), TimerState::StopwatchRunning { .. }));
+        
+        clock.advance(Duration::from_secs(125));
+        assert_eq!(app.elapsed_time(), Some(Duration::from_secs(125)));
+        assert_eq!(app.remaining_time(), None);
+        
+        // Start/stop hotkey pauses and resumes the stopwatch
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert!(matches!(app.timer_state(), TimerState::StopwatchPaused { .. }));
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert!(matches!(app.timer_state(), TimerState::StopwatchRunning { .. }));
+        
+        app.reset_timer();
+        assert!(matches!(app.timer_state(), TimerState::Stopped));
+    }
+    
+    #[test]
+    fn test_pomodoro_chains_phases() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        app.config.pomodoro.long_break_every = 2;
+        
+        app.start_pomodoro().unwrap();
+        assert_eq!(app.pomodoro_phase(), Some(PomodoroPhase::Work));
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(25 * 60)));
+        
+        // Chaining into the break is not a finish, so the notification flag stays clear
+        clock.advance(Duration::from_secs(25 * 60));
+        assert!(app.tick_timer());
+        assert!(!app.was_notification_triggered());
+        assert_eq!(app.pomodoro_phase(), Some(PomodoroPhase::ShortBreak));
+        assert!(matches!(app.timer_state(), TimerState::Running { .. }));
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(5 * 60)));
+        
+        clock.advance(Duration::from_secs(5 * 60));
+        app.tick_timer();
+        assert_eq!(app.pomodoro_phase(), Some(PomodoroPhase::Work));
+        
+        clock.advance(Duration::from_secs(25 * 60));
+        app.tick_timer();
+        assert_eq!(app.pomodoro_phase(), Some(PomodoroPhase::LongBreak));
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(15 * 60)));
+        
+        app.reset_timer();
+        assert_eq!(app.pomodoro_phase(), None);
+    }
+    
+    #[test]
+    fn test_start_default_uses_pomodoro_when_enabled() {
+        let mut app = AppState::new();
+        app.config.pomodoro.enabled = true;
+        
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert_eq!(app.pomodoro_phase(), Some(PomodoroPhase::Work));
+    }
+    
+    #[test]
+    fn test_sequence_advances_and_completes() {
+        use crate::models::sequence::SequenceStep;
+        
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let sequence = TimerSequence::new(
+            "Session",
+            vec![
+                SequenceStep::new("Warm-up", Duration::from_secs(5 * 60)),
+                SequenceStep::new("Work", Duration::from_secs(45 * 60)),
+                SequenceStep::new("Review", Duration::from_secs(10 * 60)),
+            ],
+            1,
+        );
+        
+        app.start_sequence(sequence).unwrap();
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(5 * 60)));
+        
+        clock.advance(Duration::from_secs(5 * 60));
+        assert!(app.tick_timer());
+        assert_eq!(app.sequence.as_ref().unwrap().current_step().label, "Work");
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(45 * 60)));
+        
+        // Skip and step back via hotkeys
+        app.handle_hotkey("Ctrl+Alt+N");
+        assert_eq!(app.sequence.as_ref().unwrap().current_step().label, "Review");
+        app.handle_hotkey("Ctrl+Alt+B");
+        assert_eq!(app.sequence.as_ref().unwrap().current_step().label, "Work");
+        app.skip_step().unwrap();
+        
+        // Finishing the last step finishes the whole program
+        clock.advance(Duration::from_secs(10 * 60));
+        assert!(app.tick_timer());
+        assert!(app.is_timer_finished());
+        assert!(app.sequence.is_none());
+    }
+    
+    #[test]
+    fn test_named_timers_and_hotkey_target() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        
+        let pasta = app.add_named_timer("pasta", Duration::from_secs(9 * 60)).unwrap();
+        let sauce = app.add_named_timer("sauce", Duration::from_secs(20 * 60)).unwrap();
+        assert!(app.add_named_timer("empty", Duration::ZERO).is_err());
+        assert!(app.add_named_timer("overnight", Duration::from_secs(25 * 60 * 60)).is_err());
+        assert!(!app.needs_ticking());
+        
+        // Most recently added timer receives the hotkey
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert!(app.needs_ticking());
+        assert!(matches!(app.timers.get(sauce).unwrap().timer.state(), TimerState::Running { .. }));
+        assert!(matches!(app.timer_state(), TimerState::Stopped));
+        
+        // Focus overrides recency
+        app.start_named_timer(pasta).unwrap();
+        app.focus_timer(Some(sauce));
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert!(matches!(app.timers.get(sauce).unwrap().timer.state(), TimerState::Paused { .. }));
+        
+        // Finishing a named timer rings its own alarm; the main timer's flag stays clear
+        clock.advance(Duration::from_secs(9 * 60));
+        assert!(app.tick_timer());
+        assert!(app.timers.get(pasta).unwrap().timer.is_finished());
+        assert!(app.is_ringing_for(TimerSource::Named(pasta)));
+        assert!(!app.was_notification_triggered());
+        app.acknowledge_alarm_for(TimerSource::Named(pasta));
+        assert!(!app.needs_ticking());
+        
+        // Clearing focus and using the main timer returns hotkeys to it
+        app.focus_timer(None);
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        assert_eq!(app.hotkey_target(), None);
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert!(matches!(app.timer_state(), TimerState::Paused { .. }));
+        
+        app.remove_named_timer(pasta).unwrap();
+        assert!(app.start_named_timer(pasta).is_err());
+    }
+    
+    #[test]
+    fn test_time_adjustment_hotkeys() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        
+        app.start_timer(Duration::from_secs(90)).unwrap();
+        app.handle_hotkey("Ctrl+Alt+Up");
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(150)));
+        
+        app.handle_hotkey("Ctrl+Alt+Down");
+        app.handle_hotkey("Ctrl+Alt+Down");
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(30)));
+        
+        // Subtracting past zero finishes and notifies
+        app.handle_hotkey("Ctrl+Alt+Down");
+        assert!(app.is_timer_finished());
+        assert!(app.was_notification_triggered());
+    }
+    
+    #[test]
+    fn test_time_adjustment_hotkeys_follow_named_timer() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        
+        app.start_timer(Duration::from_secs(10 * 60)).unwrap();
+        let tea = app.add_named_timer("tea", Duration::from_secs(90)).unwrap();
+        app.start_named_timer(tea).unwrap();
+        
+        // The most recent named timer is adjusted; the main timer is left alone
+        app.handle_hotkey("Ctrl+Alt+Up");
+        assert_eq!(app.timers.get(tea).unwrap().timer.remaining_time(), Some(Duration::from_secs(150)));
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(10 * 60)));
+        assert_eq!(app.hotkey_target(), Some(tea));
+        
+        // Subtracting past zero finishes and rings the named timer only
+        app.handle_hotkey("Ctrl+Alt+Down");
+        app.handle_hotkey("Ctrl+Alt+Down");
+        app.handle_hotkey("Ctrl+Alt+Down");
+        assert!(app.timers.get(tea).unwrap().timer.is_finished());
+        assert!(app.is_ringing_for(TimerSource::Named(tea)));
+        assert!(!app.was_notification_triggered());
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(10 * 60)));
+    }
+    
+    #[test]
+    fn test_configured_time_adjust_step() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let mut config = app.get_configuration();
+        config.behavior.time_adjust_step = "30s".to_string();
+        app.apply_configuration(config);
+        
+        app.start_timer(Duration::from_secs(90)).unwrap();
+        app.handle_hotkey("Ctrl+Alt+Up");
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(120)));
+        
+        // An unparseable step falls back to the default
+        app.config.behavior.time_adjust_step = "soon".to_string();
+        assert_eq!(app.time_adjust_step(), TIME_ADJUST_STEP);
+    }
+    
+    #[test]
+    fn test_format_time_uses_configured_template() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        app.config.display.time_format = "{phase} {h}:{mm}:{ss} {percent}%".to_string();
+        
+        app.start_pomodoro().unwrap();
+        clock.advance(Duration::from_secs(5 * 60));
+        let remaining = app.remaining_time().unwrap();
+        assert_eq!(app.format_time(remaining), "Work 20:00 20%");
+        
+        app.reset_timer();
+        assert_eq!(app.format_time(Duration::from_secs(2 * 60 * 60)), " 2:00:00 %");
+    }
+    
+    #[test]
+    fn test_session_snapshot_restores_after_restart() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        app.start_pomodoro().unwrap();
+        let tea = app.add_named_timer("tea", Duration::from_secs(3 * 60)).unwrap();
+        app.toggle_named_timer(tea).unwrap();
+        assert!(app.session_dirty);
+        
+        clock.advance(Duration::from_secs(60));
+        let snapshot = app.session_snapshot();
+        assert_eq!(snapshot.label.as_deref(), Some("Work 1/4"));
+        
+        // Closed for five minutes: the tea finished, the work phase did not
+        clock.suspend(Duration::from_secs(5 * 60));
+        let mut restored = AppState::with_clock(clock.clone());
+        restored.restore_session(&snapshot);
+        
+        assert_eq!(restored.pomodoro_phase(), Some(PomodoroPhase::Work));
+        assert_eq!(restored.remaining_time(), Some(Duration::from_secs(19 * 60)));
+        let named = restored.timers.iter().next().unwrap();
+        assert_eq!(named.name, "tea");
+        assert!(named.timer.is_finished());
+        assert!(restored.is_ringing_for(TimerSource::Named(named.id)));
+        assert!(!restored.was_notification_triggered());
+    }
+    
+    #[test]
+    fn test_restored_pomodoro_chains_after_missed_deadline() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        app.start_pomodoro().unwrap();
+        let snapshot = app.session_snapshot();
+        
+        clock.suspend(Duration::from_secs(30 * 60));
+        let mut restored = AppState::with_clock(clock.clone());
+        restored.restore_session(&snapshot);
+        
+        assert_eq!(restored.pomodoro_phase(), Some(PomodoroPhase::ShortBreak));
+        assert!(!restored.was_notification_triggered());
+    }
+    
+    #[test]
+    fn test_events_published_to_subscribers() {
+        use crate::models::events::EventLog;
+        
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let log = EventLog::new();
+        app.subscribe(Box::new(log.clone()));
+        
+        app.start_timer(Duration::from_secs(120)).unwrap();
+        app.pause_timer().unwrap();
+        app.resume_timer().unwrap();
+        app.add_time(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(180));
+        app.tick_timer();
+        app.reset_timer();
+        
+        assert_eq!(log.kinds(), vec![
+            TimerEventKind::Started { duration: Some(Duration::from_secs(120)) },
+            TimerEventKind::Paused,
+            TimerEventKind::Resumed,
+            TimerEventKind::TimeAdjusted { remaining: Duration::from_secs(180) },
+            TimerEventKind::Finished,
+            TimerEventKind::Acknowledged, // Resetting silences the ringing alarm
+            TimerEventKind::Reset,
+        ]);
+    }
+    
+    #[test]
+    fn test_warnings_fire_once_across_pause_and_adjustments() {
+        use crate::models::config::{WarningAction, WarningThreshold};
+        use crate::models::events::EventLog;
+        
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let mut config = app.get_configuration();
+        config.notifications.warnings = vec![
+            WarningThreshold::new("5m", vec![WarningAction::Color]),
+            WarningThreshold::new("1m", vec![WarningAction::Sound]),
+        ];
+        app.apply_configuration(config);
+        let log = EventLog::new();
+        app.subscribe(Box::new(log.clone()));
+        let warnings = || log.kinds().into_iter()
+            .filter(|kind| matches!(kind, TimerEventKind::Warning { .. }))
+            .count();
+        
+        app.start_timer(Duration::from_secs(6 * 60)).unwrap();
+        clock.advance(Duration::from_secs(61));
+        app.tick_timer();
+        assert_eq!(warnings(), 1);
+        assert!(app.active_warning().unwrap().has_action(WarningAction::Color));
+        
+        // Pausing, resuming and adding time back above the threshold never repeat it
+        app.pause_timer().unwrap();
+        app.resume_timer().unwrap();
+        app.add_time(Duration::from_secs(120)).unwrap();
+        assert!(app.active_warning().is_none());
+        clock.advance(Duration::from_secs(150));
+        app.tick_timer();
+        assert_eq!(warnings(), 1);
+        
+        // Subtracting straight past 1:00 reports that threshold immediately
+        app.subtract_time(Duration::from_secs(4 * 60)).unwrap();
+        assert_eq!(warnings(), 2);
+        assert!(log.kinds().contains(&TimerEventKind::Warning {
+            remaining: Duration::from_secs(60),
+            actions: vec![WarningAction::Sound],
+        }));
+        
+        // A fresh countdown re-arms the thresholds
+        app.reset_timer();
+        app.start_timer(Duration::from_secs(90)).unwrap();
+        clock.advance(Duration::from_secs(30));
+        app.tick_timer();
+        assert_eq!(warnings(), 3);
+    }
+    
+    #[test]
+    fn test_alarm_rings_until_acknowledged() {
+        use crate::models::events::EventLog;
+        
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let log = EventLog::new();
+        app.subscribe(Box::new(log.clone()));
+        
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(60));
+        app.tick_timer();
+        assert!(app.is_ringing());
+        app.handle_mouse_enter();
+        assert_eq!(app.get_visible_controls(), vec!["snooze".to_string(), "acknowledge".to_string()]);
+        
+        // The cue repeats every ring interval
+        for _ in 0..3 {
+            clock.advance(Duration::from_secs(10));
+            app.tick_timer();
+        }
+        assert!(log.kinds().contains(&TimerEventKind::Ringing { repeats: 3 }));
+        
+        app.handle_hotkey("Ctrl+Alt+A");
+        assert!(!app.is_ringing());
+        assert_eq!(app.timer_state(), &TimerState::Stopped);
+        
+        // Unacknowledged alarms stop after the maximum ring duration
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(60));
+        app.tick_timer();
+        clock.advance(Duration::from_secs(5 * 60));
+        app.tick_timer();
+        assert!(!app.is_ringing());
+        assert_eq!(log.kinds().last(), Some(&TimerEventKind::RingTimedOut));
+    }
+    
+    #[test]
+    fn test_snooze_restarts_short_countdown() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let mut config = app.get_configuration();
+        config.notifications.snooze_duration = "2m".to_string();
+        app.apply_configuration(config);
+        
+        assert!(app.snooze_alarm().is_err());
+        
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(60));
+        app.tick_timer();
+        assert!(app.was_notification_triggered());
+        app.handle_hotkey("Ctrl+Alt+Z");
+        assert!(!app.is_ringing());
+        assert!(!app.was_notification_triggered());
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(120)));
+        
+        // Named timers snooze on their own timer
+        let tea = app.add_named_timer("tea", Duration::from_secs(30)).unwrap();
+        app.start_named_timer(tea).unwrap();
+        clock.advance(Duration::from_secs(30));
+        app.tick_timer();
+        assert!(app.is_ringing_for(TimerSource::Named(tea)));
+        app.snooze_alarm().unwrap();
+        assert_eq!(app.timers.get(tea).unwrap().timer.remaining_time(), Some(Duration::from_secs(120)));
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(90)));
+    }
+    
+    #[test]
+    fn test_alarms_ring_per_timer() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let tea = app.add_named_timer("tea", Duration::from_secs(30)).unwrap();
+        let eggs = app.add_named_timer("eggs", Duration::from_secs(40)).unwrap();
+        app.start_named_timer(tea).unwrap();
+        app.start_named_timer(eggs).unwrap();
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        
+        // A second timer finishing does not silence the first
+        clock.advance(Duration::from_secs(30));
+        app.tick_timer();
+        clock.advance(Duration::from_secs(10));
+        app.tick_timer();
+        assert!(app.is_ringing_for(TimerSource::Named(tea)));
+        assert!(app.is_ringing_for(TimerSource::Named(eggs)));
+        assert!(!app.is_ringing_for(TimerSource::Main));
+        
+        // Alarms are answered oldest first, or one timer at a time
+        app.acknowledge_alarm();
+        assert!(!app.is_ringing_for(TimerSource::Named(tea)));
+        assert!(app.is_ringing_for(TimerSource::Named(eggs)));
+        
+        clock.advance(Duration::from_secs(20));
+        app.tick_timer();
+        assert!(app.is_ringing_for(TimerSource::Main));
+        app.acknowledge_alarm_for(TimerSource::Main);
+        assert_eq!(app.timer_state(), &TimerState::Stopped);
+        assert!(app.is_ringing_for(TimerSource::Named(eggs)));
+        
+        // Each alarm times out on its own schedule
+        clock.advance(Duration::from_secs(4 * 60 + 40));
+        app.tick_timer();
+        assert!(!app.is_ringing());
+    }
+    
+    #[test]
+    fn test_no_ringing_when_repeat_disabled() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let mut config = app.get_configuration();
+        config.notifications.repeat_until_acknowledged = false;
+        app.apply_configuration(config);
+        
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(60));
+        app.tick_timer();
+        assert!(app.is_timer_finished());
+        assert!(!app.is_ringing());
+    }
+    
+    #[test]
+    fn test_chained_phases_and_named_timers_publish_events() {
+        use crate::models::events::EventLog;
+        
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let log = EventLog::new();
+        app.subscribe(Box::new(log.clone()));
+        
+        app.start_pomodoro().unwrap();
+        let tea = app.add_named_timer("tea", Duration::from_secs(3 * 60)).unwrap();
+        app.toggle_named_timer(tea).unwrap();
+        log.clear();
+        
+        clock.advance(Duration::from_secs(25 * 60));
+        app.tick_timer();
+        
+        let events = log.events();
+        assert!(events.contains(&TimerEvent::new(
+            TimerSource::Main,
+            TimerEventKind::PhaseChanged { label: "Short Break 1/4".to_string(), automatic: true },
+        )));
+        assert!(events.contains(&TimerEvent {
+            source: TimerSource::Named(tea),
+            kind: TimerEventKind::Finished,
+            name: Some("tea".to_string()),
+        }));
+        // The work phase chained into a break, so the main timer never reports Finished
+        assert!(events.iter()
+            .filter(|e| e.kind == TimerEventKind::Finished)
+            .all(|e| e.source == TimerSource::Named(tea)));
+    }
+    
+    #[test]
+    fn test_overtime_until_dismissed() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let mut config = app.get_configuration();
+        config.behavior.overtime_enabled = true;
+        app.apply_configuration(config);
+        
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(60 + 133));
+        assert!(app.tick_timer());
+        assert!(app.is_timer_finished());
+        assert!(app.was_notification_triggered());
+        assert_eq!(app.overtime(), Some(Duration::from_secs(133)));
+        
+        app.handle_mouse_enter();
+        assert_eq!(app.get_visible_controls(), vec!["dismiss".to_string()]);
+        
+        // Start/stop hotkey dismisses overtime
+        app.handle_hotkey("Ctrl+Alt+S");
+        assert!(matches!(app.timer_state(), TimerState::Stopped));
+    }
+    
+    #[test]
+    fn test_shared_clock_drives_timer_and_ui() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        
+        app.start_timer(Duration::from_secs(60)).unwrap();
+        assert_eq!(app.ui_state.time_since_interaction(), Some(Duration::ZERO));
+        
+        clock.advance(Duration::from_secs(59));
+        assert!(!app.tick_timer());
+        assert_eq!(app.remaining_time(), Some(Duration::from_secs(1)));
+        assert_eq!(app.ui_state.time_since_interaction(), Some(Duration::from_secs(59)));
+        assert!(app.ui_state.should_auto_hide(Duration::from_secs(5)));
+        
+        clock.advance(Duration::from_secs(1));
+        assert!(app.tick_timer());
+        assert!(app.was_notification_triggered());
+    }
+    
+    #[test]
+    fn test_alarm_replaces_pomodoro_and_notifies() {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        app.start_pomodoro().unwrap();
+        app.reset_timer();
+        
+        let target = clock.wall_now() + Duration::from_secs(90);
+        app.start_alarm(target).unwrap();
+        assert!(app.pomodoro.is_none());
+        assert_eq!(app.alarm_target(), Some(target));
+        
+        clock.advance(Duration::from_secs(90));
+        assert!(app.tick_timer());
+        assert!(app.is_timer_finished());
+        assert!(app.was_notification_triggered());
+        assert!(app.pomodoro.is_none());
+    }
 }
\ No newline at end of file
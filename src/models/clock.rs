@@ -0,0 +1,156 @@
+// Clock abstraction so timer logic can run against real or simulated time
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Source of monotonic and wall-clock time used by timers and UI state
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Current monotonic instant
+    fn now(&self) -> Instant;
+
+    /// Current wall-clock time, which keeps advancing while the system is suspended
+    fn wall_now(&self) -> SystemTime;
+
+    /// Time elapsed since an earlier instant taken from this clock
+    fn elapsed_since(&self, earlier: Instant) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+/// Shared handle to a clock, cloned into every component that needs time
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Real monotonic clock backed by `Instant::now()`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// Create a shared handle to the system clock
+    pub fn shared() -> SharedClock {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn wall_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Manually driven clock for deterministic tests and simulations
+///
+/// Time only moves when `advance` is called, so a full countdown can be
+/// simulated without sleeping. `suspend` moves only the wall clock, mimicking
+/// platforms where the monotonic clock stops during sleep, and `set_wall_back`
+/// mimics the user or NTP moving the system clock backwards.
+#[derive(Debug)]
+pub struct ManualClock {
+    origin: Instant,
+    wall_origin: SystemTime,
+    offset: Mutex<Duration>,
+    wall_offset: Mutex<Duration>,
+    wall_rewind: Mutex<Duration>,
+}
+
+impl ManualClock {
+    /// Create a manual clock frozen at the current instant
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            wall_origin: Self::whole_second_now(),
+            offset: Mutex::new(Duration::ZERO),
+            wall_offset: Mutex::new(Duration::ZERO),
+            wall_rewind: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Current wall time truncated to whole seconds, so persisted timestamps round-trip exactly
+    fn whole_second_now() -> SystemTime {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        UNIX_EPOCH + Duration::from_secs(since_epoch.as_secs())
+    }
+
+    /// Create a shared manual clock
+    pub fn shared() -> Arc<ManualClock> {
+        Arc::new(Self::new())
+    }
+
+    /// Move the clock forward by the given amount
+    pub fn advance(&self, step: Duration) {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner()) += step;
+        *self.wall_offset.lock().unwrap_or_else(|e| e.into_inner()) += step;
+    }
+
+    /// Simulate a system suspend: wall time moves, monotonic time does not
+    pub fn suspend(&self, duration: Duration) {
+        *self.wall_offset.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+    }
+
+    /// Move only the wall clock backwards, as when the system time is adjusted
+    pub fn set_wall_back(&self, amount: Duration) {
+        *self.wall_rewind.lock().unwrap_or_else(|e| e.into_inner()) += amount;
+    }
+
+    /// Total time this clock has been advanced since creation
+    pub fn total_advanced(&self) -> Duration {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.total_advanced()
+    }
+
+    fn wall_now(&self) -> SystemTime {
+        let forward = *self.wall_offset.lock().unwrap_or_else(|e| e.into_inner());
+        let back = *self.wall_rewind.lock().unwrap_or_else(|e| e.into_inner());
+        self.wall_origin + forward - back
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_only_moves_when_advanced() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.elapsed_since(start), Duration::from_secs(90));
+    }
+
+    #[test]
+    fn test_manual_clock_suspend_moves_wall_time_only() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let wall_start = clock.wall_now();
+
+        clock.suspend(Duration::from_secs(600));
+        assert_eq!(clock.now(), start);
+        assert_eq!(
+            clock.wall_now().duration_since(wall_start).unwrap(),
+            Duration::from_secs(600)
+        );
+    }
+
+    #[test]
+    fn test_system_clock_is_monotonic() {
+        let clock = SystemClock;
+        let first = clock.now();
+        let second = clock.now();
+        assert!(second >= first);
+    }
+}
\ No newline at end of file
@@ -1,7 +1,33 @@
 // Configuration data structures with serde serialization support
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::path::PathBuf;
 
+use crate::models::duration_parser::parse_duration;
+use crate::models::sequence::TimerSequence;
+use crate::models::time_format::{TimeFormat, DEFAULT_TIME_FORMAT};
+
+/// Schema version written to new configuration files; see `services::config_migration`
+pub const CONFIG_VERSION: &str = "2.1";
+
+/// Most times a sound file may play back to back for one cue
+pub const MAX_SOUND_LOOP_COUNT: u32 = 100;
+
+/// Read an optional setting, taking an empty string as `None`
+///
+/// TOML has no null, so configuration files in TOML spell a cleared setting,
+/// such as a disabled hotkey, as `""`.
+fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Null => Ok(None),
+        serde_json::Value::String(text) if text.is_empty() => Ok(None),
+        value => serde_json::from_value(value).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Configuration {
@@ -10,6 +36,8 @@ pub struct Configuration {
     pub behavior: BehaviorConfig,
     pub hotkeys: HotkeyConfig,
     pub notifications: NotificationConfig,
+    pub pomodoro: PomodoroConfig,
+    pub sequences: Vec<TimerSequence>, // Saved interval programs
 }
 
 /// Display-related configuration
@@ -18,8 +46,11 @@ pub struct DisplayConfig {
     pub transparency: f32,           // 0.0 (transparent) to 1.0 (opaque)
     pub hover_transparency: f32,     // Transparency when hovered
     pub position: (i32, i32),        // Screen coordinates (logical pixels)
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub text_color: Option<Color>,   // None = auto-detect, Some = manual
     pub show_controls: bool,         // Show start/pause buttons
+    pub time_format: String,         // Template, e.g., "{h}:{mm}:{ss}" or "{total_minutes} min"
+    pub collapse_leading_zeros: bool, // Hide the hours field while it is zero
 }
 
 /// Behavior-related configuration
@@ -29,14 +60,34 @@ pub struct BehaviorConfig {
     pub remember_position: bool,
     pub auto_detect_background: bool,
     pub minimize_to_tray: bool,
+    pub overtime_enabled: bool,      // Count up past zero until dismissed
+    pub time_adjust_step: String,    // Added/removed per adjustment, e.g., "1m" or "30s"
+    pub config_backups: u32,         // Timestamped copies kept after each save; 0 disables
 }
 
 /// Hotkey configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HotkeyConfig {
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub toggle_visibility: Option<String>,  // e.g., "Ctrl+Alt+T"
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub start_stop: Option<String>,         // e.g., "Ctrl+Alt+S"
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub reset: Option<String>,              // e.g., "Ctrl+Alt+R"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub record_lap: Option<String>,         // e.g., "Ctrl+Alt+L"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub skip_step: Option<String>,          // e.g., "Ctrl+Alt+N"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub previous_step: Option<String>,      // e.g., "Ctrl+Alt+B"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub add_time: Option<String>,           // e.g., "Ctrl+Alt+Up"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub subtract_time: Option<String>,      // e.g., "Ctrl+Alt+Down"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub acknowledge: Option<String>,        // e.g., "Ctrl+Alt+A"
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub snooze: Option<String>,             // e.g., "Ctrl+Alt+Z"
 }
 
 /// Notification configuration
@@ -45,7 +96,52 @@ pub struct NotificationConfig {
     pub sound_enabled: bool,
     pub visual_flash: bool,
     pub system_notification: bool,
-    pub sound_file: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub sound_file: Option<String>,      // WAV, OGG or MP3; None plays the system sound
+    pub sound_volume: f32,               // 0.0 (silent) to 1.0 (full)
+    pub sound_loop_count: u32,           // Times the sound file plays per cue
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub log_file: Option<String>,        // Append each notification to this file
+    pub warnings: Vec<WarningThreshold>, // Pre-finish cues, checked while counting down
+    pub repeat_until_acknowledged: bool, // Keep ringing after a countdown finishes
+    pub ring_interval: String,           // Time between repeated cues, e.g., "10s"
+    pub max_ring_duration: String,       // Ringing stops on its own after this, e.g., "5m"
+    pub snooze_duration: String,         // Countdown restarted by a snooze, e.g., "5m"
+}
+
+/// Cue given when a warning threshold is crossed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WarningAction {
+    Color,        // Tint the time display
+    Pulse,        // Fade the time display in and out
+    Sound,
+    Notification, // Ask the system for the user's attention
+}
+
+/// Warning given once a countdown has this much time left
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarningThreshold {
+    pub remaining: String,           // e.g., "5m" or "0:10"
+    pub actions: Vec<WarningAction>,
+}
+
+impl WarningThreshold {
+    pub fn new(remaining: &str, actions: Vec<WarningAction>) -> Self {
+        Self {
+            remaining: remaining.to_string(),
+            actions,
+        }
+    }
+}
+
+/// Pomodoro cycle configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PomodoroConfig {
+    pub enabled: bool,            // Start actions begin a Pomodoro session
+    pub work_minutes: u32,
+    pub short_break_minutes: u32,
+    pub long_break_minutes: u32,
+    pub long_break_every: u32,    // Long break after every N work sessions
 }
 
 /// Color representation
@@ -86,7 +182,7 @@ pub enum ConfigError {
     FileNotFound,
     InvalidFormat(String),
     WriteError(String),
-    ValidationFailed(Vec<ValidationError>),
+    ValidationFailed(Vec<FieldError>),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -96,7 +192,11 @@ impl std::fmt::Display for ConfigError {
             ConfigError::InvalidFormat(msg) => write!(f, "Invalid configuration format: {}", msg),
             ConfigError::WriteError(msg) => write!(f, "Failed to write configuration: {}", msg),
             ConfigError::ValidationFailed(errors) => {
-                write!(f, "Configuration validation failed: {} errors", errors.len())
+                write!(f, "Configuration validation failed: {} errors", errors.len())?;
+                for error in errors {
+                    write!(f, "; {}", error)?;
+                }
+                Ok(())
             }
         }
     }
@@ -111,6 +211,11 @@ pub enum ValidationError {
     InvalidPosition(i32, i32),
     InvalidHotkey(String),
     InvalidSoundFile(String),
+    InvalidDuration(String),
+    InvalidSequence(String),
+    InvalidTimeFormat(String),
+    InvalidVolume(f32),
+    InvalidLoopCount(u32),
 }
 
 impl std::fmt::Display for ValidationError {
@@ -128,10 +233,47 @@ impl std::fmt::Display for ValidationError {
             ValidationError::InvalidSoundFile(path) => {
                 write!(f, "Invalid sound file: '{}' (file not found or unsupported format)", path)
             }
+            ValidationError::InvalidDuration(field) => {
+                write!(f, "Invalid duration setting: '{}' (out of allowed range)", field)
+            }
+            ValidationError::InvalidSequence(msg) => write!(f, "Invalid timer sequence: {}", msg),
+            ValidationError::InvalidTimeFormat(token) => {
+                write!(f, "Invalid time format: unknown or unclosed token '{}'", token)
+            }
+            ValidationError::InvalidVolume(value) => {
+                write!(f, "Invalid sound volume: {} (must be 0.0-1.0)", value)
+            }
+            ValidationError::InvalidLoopCount(count) => {
+                write!(f, "Invalid sound loop count: {} (must be 1-{})", count, MAX_SOUND_LOOP_COUNT)
+            }
         }
     }
 }
 
+/// A validation error at a field of the configuration file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub path: String, // JSON path, e.g. "display.transparency" or "notifications.warnings[1].remaining"
+    pub error: ValidationError,
+}
+
+impl FieldError {
+    pub fn new(path: impl Into<String>, error: ValidationError) -> Self {
+        Self { path: path.into(), error }
+    }
+
+    /// Same error, with its path placed under the given section
+    fn in_section(self, section: &str) -> Self {
+        Self::new(format!("{}.{}", section, self.path), self.error)
+    }
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
 /// Hotkey-related errors
 #[derive(Debug, Clone, PartialEq)]
 pub enum HotkeyError {
@@ -157,11 +299,13 @@ impl std::error::Error for HotkeyError {}
 impl Default for Configuration {
     fn default() -> Self {
         Self {
-            version: "1.0".to_string(),
+            version: CONFIG_VERSION.to_string(),
             display: DisplayConfig::default(),
             behavior: BehaviorConfig::default(),
             hotkeys: HotkeyConfig::default(),
             notifications: NotificationConfig::default(),
+            pomodoro: PomodoroConfig::default(),
+            sequences: Vec::new(),
         }
     }
 }
@@ -174,6 +318,8 @@ impl Default for DisplayConfig {
             position: (100, 100),     // Default position with margin
             text_color: None,         // Auto-detect
             show_controls: true,      // Show controls by default
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            collapse_leading_zeros: true,
         }
     }
 }
@@ -185,6 +331,9 @@ impl Default for BehaviorConfig {
             remember_position: true,
             auto_detect_background: true,
             minimize_to_tray: false,
+            overtime_enabled: false,
+            time_adjust_step: "1m".to_string(),
+            config_backups: 10,
         }
     }
 }
@@ -195,6 +344,13 @@ impl Default for HotkeyConfig {
             toggle_visibility: Some("Ctrl+Alt+T".to_string()),
             start_stop: Some("Ctrl+Alt+S".to_string()),
             reset: Some("Ctrl+Alt+R".to_string()),
+            record_lap: Some("Ctrl+Alt+L".to_string()),
+            skip_step: Some("Ctrl+Alt+N".to_string()),
+            previous_step: Some("Ctrl+Alt+B".to_string()),
+            add_time: Some("Ctrl+Alt+Up".to_string()),
+            subtract_time: Some("Ctrl+Alt+Down".to_string()),
+            acknowledge: Some("Ctrl+Alt+A".to_string()),
+            snooze: Some("Ctrl+Alt+Z".to_string()),
         }
     }
 }
@@ -206,6 +362,29 @@ impl Default for NotificationConfig {
             visual_flash: true,
             system_notification: true,
             sound_file: None, // Use default system sound
+            sound_volume: 1.0,
+            sound_loop_count: 1,
+            log_file: None,
+            warnings: vec![
+                WarningThreshold::new("1m", vec![WarningAction::Color]),
+                WarningThreshold::new("10s", vec![WarningAction::Color, WarningAction::Pulse]),
+            ],
+            repeat_until_acknowledged: true,
+            ring_interval: "10s".to_string(),
+            max_ring_duration: "5m".to_string(),
+            snooze_duration: "5m".to_string(),
+        }
+    }
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            long_break_every: 4,
         }
     }
 }
@@ -214,16 +393,28 @@ impl Default for NotificationConfig {
 impl Configuration {
     /// Validate all configuration values
     pub fn validate(&self) -> Vec<ValidationError> {
+        self.validate_fields().into_iter().map(|e| e.error).collect()
+    }
+    
+    /// Validate all configuration values, locating each error by its JSON path
+    pub fn validate_fields(&self) -> Vec<FieldError> {
         let mut errors = Vec::new();
         
-        // Validate display configuration
-        errors.extend(self.display.validate());
-        
-        // Validate hotkeys
-        errors.extend(self.hotkeys.validate());
+        let sections = [
+            ("display", self.display.validate_fields()),
+            ("behavior", self.behavior.validate_fields()),
+            ("hotkeys", self.hotkeys.validate_fields()),
+            ("notifications", self.notifications.validate_fields()),
+            ("pomodoro", self.pomodoro.validate_fields()),
+        ];
+        for (section, section_errors) in sections {
+            errors.extend(section_errors.into_iter().map(|e| e.in_section(section)));
+        }
         
-        // Validate notifications
-        errors.extend(self.notifications.validate());
+        // Validate saved sequences
+        for (index, sequence) in self.sequences.iter().enumerate() {
+            errors.extend(sequence_field_errors(sequence, index));
+        }
         
         errors
     }
@@ -235,60 +426,111 @@ impl Configuration {
 }
 
 impl DisplayConfig {
+    /// Parsed time format, falling back to the default template if invalid
+    pub fn parsed_time_format(&self) -> TimeFormat {
+        let mut format = TimeFormat::parse_or_default(&self.time_format);
+        format.collapse_leading_zeros = self.collapse_leading_zeros;
+        format
+    }
+    
     /// Validate display configuration
     pub fn validate(&self) -> Vec<ValidationError> {
+        self.validate_fields().into_iter().map(|e| e.error).collect()
+    }
+    
+    /// Validate display configuration; paths are relative to the section
+    pub fn validate_fields(&self) -> Vec<FieldError> {
         let mut errors = Vec::new();
         
         // Validate transparency values
         if self.transparency < 0.0 || self.transparency > 1.0 {
-            errors.push(ValidationError::InvalidTransparency(self.transparency));
+            errors.push(FieldError::new("transparency", ValidationError::InvalidTransparency(self.transparency)));
         }
         
         if self.hover_transparency < 0.0 || self.hover_transparency > 1.0 {
-            errors.push(ValidationError::InvalidTransparency(self.hover_transparency));
+            errors.push(FieldError::new(
+                "hover_transparency",
+                ValidationError::InvalidTransparency(self.hover_transparency),
+            ));
         }
         
         // Hover transparency should be >= base transparency for usability
         if self.hover_transparency < self.transparency {
-            errors.push(ValidationError::InvalidTransparency(self.hover_transparency));
+            errors.push(FieldError::new(
+                "hover_transparency",
+                ValidationError::InvalidTransparency(self.hover_transparency),
+            ));
         }
         
         // Validate position (basic bounds check - detailed validation needs monitor info)
         let (x, y) = self.position;
         if x < -5000 || x > 10000 || y < -5000 || y > 10000 {
-            errors.push(ValidationError::InvalidPosition(x, y));
+            errors.push(FieldError::new("position", ValidationError::InvalidPosition(x, y)));
+        }
+        
+        // Validate the time format template
+        if let Err(token) = TimeFormat::parse(&self.time_format) {
+            errors.push(FieldError::new("time_format", ValidationError::InvalidTimeFormat(token)));
         }
         
         errors
     }
 }
 
-impl HotkeyConfig {
-    /// Validate hotkey configuration
+impl BehaviorConfig {
+    /// Validate behavior configuration
     pub fn validate(&self) -> Vec<ValidationError> {
+        self.validate_fields().into_iter().map(|e| e.error).collect()
+    }
+    
+    /// Validate behavior configuration; paths are relative to the section
+    pub fn validate_fields(&self) -> Vec<FieldError> {
         let mut errors = Vec::new();
         
-        // Validate each hotkey if present
-        if let Some(ref keys) = self.toggle_visibility {
-            if !Self::is_valid_hotkey(keys) {
-                errors.push(ValidationError::InvalidHotkey(keys.clone()));
-            }
-        }
-        
-        if let Some(ref keys) = self.start_stop {
-            if !Self::is_valid_hotkey(keys) {
-                errors.push(ValidationError::InvalidHotkey(keys.clone()));
-            }
-        }
-        
-        if let Some(ref keys) = self.reset {
-            if !Self::is_valid_hotkey(keys) {
-                errors.push(ValidationError::InvalidHotkey(keys.clone()));
-            }
+        // The adjustment step must parse to a non-zero duration
+        match parse_duration(&self.time_adjust_step) {
+            Ok(step) if !step.is_zero() => {}
+            _ => errors.push(FieldError::new(
+                "time_adjust_step",
+                ValidationError::InvalidDuration("time_adjust_step".to_string()),
+            )),
         }
         
         errors
     }
+}
+
+impl HotkeyConfig {
+    /// Validate hotkey configuration
+    pub fn validate(&self) -> Vec<ValidationError> {
+        self.validate_fields().into_iter().map(|e| e.error).collect()
+    }
+    
+    /// Validate hotkey configuration; paths are relative to the section
+    pub fn validate_fields(&self) -> Vec<FieldError> {
+        let hotkeys = [
+            ("toggle_visibility", &self.toggle_visibility),
+            ("start_stop", &self.start_stop),
+            ("reset", &self.reset),
+            ("record_lap", &self.record_lap),
+            ("skip_step", &self.skip_step),
+            ("previous_step", &self.previous_step),
+            ("add_time", &self.add_time),
+            ("subtract_time", &self.subtract_time),
+            ("acknowledge", &self.acknowledge),
+            ("snooze", &self.snooze),
+        ];
+        
+        // Validate each hotkey if present
+        hotkeys
+            .into_iter()
+            .filter_map(|(field, keys)| {
+                let keys = keys.as_ref()?;
+                (!Self::is_valid_hotkey(keys))
+                    .then(|| FieldError::new(field, ValidationError::InvalidHotkey(keys.clone())))
+            })
+            .collect()
+    }
     
     /// Basic hotkey validation (more detailed validation in hotkey manager)
     fn is_valid_hotkey(keys: &str) -> bool {
@@ -302,19 +544,100 @@ impl HotkeyConfig {
 impl NotificationConfig {
     /// Validate notification configuration
     pub fn validate(&self) -> Vec<ValidationError> {
+        self.validate_fields().into_iter().map(|e| e.error).collect()
+    }
+    
+    /// Validate notification configuration; paths are relative to the section
+    pub fn validate_fields(&self) -> Vec<FieldError> {
         let mut errors = Vec::new();
         
-        // Validate sound file if specified
+        // Validate sound file if specified; whether it decodes is checked by the config manager
         if let Some(ref sound_file) = self.sound_file {
             if !sound_file.is_empty() && !PathBuf::from(sound_file).exists() {
-                errors.push(ValidationError::InvalidSoundFile(sound_file.clone()));
+                errors.push(FieldError::new("sound_file", ValidationError::InvalidSoundFile(sound_file.clone())));
+            }
+        }
+        
+        if !(0.0..=1.0).contains(&self.sound_volume) {
+            errors.push(FieldError::new("sound_volume", ValidationError::InvalidVolume(self.sound_volume)));
+        }
+        
+        if !(1..=MAX_SOUND_LOOP_COUNT).contains(&self.sound_loop_count) {
+            errors.push(FieldError::new(
+                "sound_loop_count",
+                ValidationError::InvalidLoopCount(self.sound_loop_count),
+            ));
+        }
+        
+        // Thresholds must be non-zero durations
+        for (index, warning) in self.warnings.iter().enumerate() {
+            if !matches!(parse_duration(&warning.remaining), Ok(d) if !d.is_zero()) {
+                let path = format!("warnings[{}].remaining", index);
+                errors.push(FieldError::new(path.clone(), ValidationError::InvalidDuration(path)));
+            }
+        }
+        
+        // Ringing durations must be non-zero
+        for (field, value) in [
+            ("ring_interval", &self.ring_interval),
+            ("max_ring_duration", &self.max_ring_duration),
+            ("snooze_duration", &self.snooze_duration),
+        ] {
+            if !matches!(parse_duration(value), Ok(d) if !d.is_zero()) {
+                errors.push(FieldError::new(field, ValidationError::InvalidDuration(field.to_string())));
+            }
+        }
+        
+        errors
+    }
+}
+
+impl PomodoroConfig {
+    /// Validate pomodoro configuration
+    pub fn validate(&self) -> Vec<ValidationError> {
+        self.validate_fields().into_iter().map(|e| e.error).collect()
+    }
+    
+    /// Validate pomodoro configuration; paths are relative to the section
+    pub fn validate_fields(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        
+        // Each phase must fit within the timer's 1 minute - 24 hour range
+        let phases = [
+            ("work_minutes", self.work_minutes),
+            ("short_break_minutes", self.short_break_minutes),
+            ("long_break_minutes", self.long_break_minutes),
+        ];
+        for (field, minutes) in phases {
+            if minutes == 0 || minutes > 24 * 60 {
+                errors.push(FieldError::new(field, ValidationError::InvalidDuration(field.to_string())));
             }
         }
         
+        if self.long_break_every == 0 {
+            errors.push(FieldError::new(
+                "long_break_every",
+                ValidationError::InvalidDuration("long_break_every".to_string()),
+            ));
+        }
+        
         errors
     }
 }
 
+/// Locate a saved sequence's errors: step durations already name their field,
+/// anything else concerns the sequence as a whole
+pub fn sequence_field_errors(sequence: &TimerSequence, index: usize) -> Vec<FieldError> {
+    sequence
+        .validate(index)
+        .into_iter()
+        .map(|error| match &error {
+            ValidationError::InvalidDuration(path) => FieldError::new(path.clone(), error),
+            _ => FieldError::new(format!("sequences[{}]", index), error),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,6 +711,82 @@ mod tests {
         assert_eq!(config.behavior.always_on_top, deserialized.behavior.always_on_top);
     }
     
+    #[test]
+    fn test_invalid_pomodoro_validation() {
+        let mut config = Configuration::default();
+        config.pomodoro.work_minutes = 0;
+        config.pomodoro.long_break_every = 0;
+        
+        let errors = config.validate();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains(&ValidationError::InvalidDuration("work_minutes".to_string())));
+    }
+    
+    #[test]
+    fn test_invalid_time_format_validation() {
+        let mut config = Configuration::default();
+        config.display.time_format = "{mm}:{ss}.{ms}".to_string();
+        
+        let errors = config.validate();
+        assert_eq!(errors, vec![ValidationError::InvalidTimeFormat("{ms".to_string())]);
+    }
+    
+    #[test]
+    fn test_invalid_time_adjust_step_validation() {
+        let mut config = Configuration::default();
+        config.behavior.time_adjust_step = "90s".to_string();
+        assert!(config.validate().is_empty());
+        
+        config.behavior.time_adjust_step = "a while".to_string();
+        let errors = config.validate();
+        assert_eq!(errors, vec![ValidationError::InvalidDuration("time_adjust_step".to_string())]);
+    }
+    
+    #[test]
+    fn test_invalid_warning_threshold_validation() {
+        let mut config = Configuration::default();
+        config.notifications.warnings.push(WarningThreshold::new("0:30", vec![WarningAction::Sound]));
+        assert!(config.validate().is_empty());
+        
+        config.notifications.warnings.push(WarningThreshold::new("0s", vec![WarningAction::Sound]));
+        let errors = config.validate();
+        assert_eq!(errors, vec![ValidationError::InvalidDuration("warnings[3].remaining".to_string())]);
+    }
+    
+    #[test]
+    fn test_invalid_ring_duration_validation() {
+        let mut config = Configuration::default();
+        config.notifications.snooze_duration = "0:00".to_string();
+        config.notifications.ring_interval = "soon".to_string();
+        let errors = config.validate();
+        assert_eq!(errors, vec![
+            ValidationError::InvalidDuration("ring_interval".to_string()),
+            ValidationError::InvalidDuration("snooze_duration".to_string()),
+        ]);
+    }
+    
+    #[test]
+    fn test_invalid_sound_settings_validation() {
+        let mut config = Configuration::default();
+        config.notifications.sound_volume = 1.5;
+        config.notifications.sound_loop_count = 0;
+        config.notifications.sound_file = Some("missing_sound.wav".to_string());
+        
+        let errors = config.validate();
+        assert_eq!(errors, vec![
+            ValidationError::InvalidSoundFile("missing_sound.wav".to_string()),
+            ValidationError::InvalidVolume(1.5),
+            ValidationError::InvalidLoopCount(0),
+        ]);
+        
+        config.notifications.sound_volume = 1.0;
+        config.notifications.sound_file = None;
+        config.notifications.sound_loop_count = MAX_SOUND_LOOP_COUNT;
+        assert!(config.validate().is_empty());
+        config.notifications.sound_loop_count = MAX_SOUND_LOOP_COUNT + 1;
+        assert_eq!(config.validate(), vec![ValidationError::InvalidLoopCount(MAX_SOUND_LOOP_COUNT + 1)]);
+    }
+    
     #[test]
     fn test_hover_transparency_validation() {
         let mut config = Configuration::default();
@@ -398,4 +797,24 @@ mod tests {
         assert!(!errors.is_empty());
         assert!(errors.iter().any(|e| matches!(e, ValidationError::InvalidTransparency(_))));
     }
+    
+    #[test]
+    fn test_validation_errors_carry_json_paths() {
+        let mut config = Configuration::default();
+        config.display.hover_transparency = 1.5;
+        config.hotkeys.snooze = Some("Ctrl++Z".to_string());
+        config.notifications.warnings[1].remaining = "0s".to_string();
+        config.pomodoro.long_break_every = 0;
+        config.sequences.push(TimerSequence::new("Empty", Vec::new(), 1));
+        
+        let paths: Vec<String> = config.validate_fields().into_iter().map(|e| e.path).collect();
+        assert_eq!(paths, vec![
+            "display.hover_transparency",
+            "hotkeys.snooze",
+            "notifications.warnings[1].remaining",
+            "pomodoro.long_break_every",
+            "sequences[0]",
+        ]);
+        assert_eq!(config.validate().len(), 5);
+    }
 }
\ No newline at end of file
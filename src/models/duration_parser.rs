@@ -0,0 +1,278 @@
+// Flexible duration input: "1:30:00", "90s", "1h 15m", "1.5h", "PT45M", "in 10 minutes"
+use std::time::Duration;
+
+/// Largest duration the parser will produce (one year); timers apply their own caps
+const MAX_PARSED_SECS: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// Duration parsing errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum DurationParseError {
+    Empty,
+    InvalidNumber(String),
+    UnknownUnit(String),
+    FieldOutOfRange(String), // e.g. the seconds in "5:75"
+    TooLarge(String),
+    InvalidFormat(String),
+}
+
+impl std::fmt::Display for DurationParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationParseError::Empty => write!(f, "No duration given"),
+            DurationParseError::InvalidNumber(text) => write!(f, "Invalid number: '{}'", text),
+            DurationParseError::UnknownUnit(unit) => {
+                write!(f, "Unknown time unit: '{}' (use h, m or s)", unit)
+            }
+            DurationParseError::FieldOutOfRange(field) => {
+                write!(f, "Value out of range: '{}' (minutes and seconds must be below 60)", field)
+            }
+            DurationParseError::TooLarge(text) => write!(f, "Duration is too large: '{}'", text),
+            DurationParseError::InvalidFormat(text) => write!(f, "Unrecognized duration: '{}'", text),
+        }
+    }
+}
+
+impl std::error::Error for DurationParseError {}
+
+/// Parse a duration typed by the user
+///
+/// Accepted forms:
+/// - clock style `mm:ss` or `h:mm:ss` (the leading field is unbounded)
+/// - units `90s`, `25m`, `1h 15m`, `1.5h`, `2 hours and 5 minutes`
+/// - ISO 8601 `PT45M`, `PT1H30M`, `P1DT2H`
+/// - phrases `in 10 minutes`, `an hour`
+///
+/// A bare number is read as minutes, matching the widget's default unit.
+pub fn parse_duration(input: &str) -> Result<Duration, DurationParseError> {
+    let text = input.trim().to_ascii_lowercase();
+    let text = text.strip_prefix("in ").unwrap_or(&text).trim();
+    if text.is_empty() {
+        return Err(DurationParseError::Empty);
+    }
+
+    let secs = if text.contains(':') {
+        parse_clock(text)?
+    } else if text.starts_with('p') {
+        parse_iso8601(text)?
+    } else {
+        parse_units(text)?
+    };
+
+    if !secs.is_finite() || secs > MAX_PARSED_SECS {
+        return Err(DurationParseError::TooLarge(input.trim().to_string()));
+    }
+    Ok(Duration::from_millis((secs * 1000.0).round() as u64))
+}
+
+/// Format a duration as `mm:ss`, or `h:mm:ss` from one hour up
+///
+/// The output is always accepted by `parse_duration` and parses back to the
+/// same whole number of seconds.
+pub fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, total % 3600 / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+fn parse_number(text: &str) -> Result<f64, DurationParseError> {
+    let valid = !text.is_empty()
+        && text.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && text.chars().filter(|&c| c == '.').count() <= 1
+        && text != ".";
+    if !valid {
+        return Err(DurationParseError::InvalidNumber(text.to_string()));
+    }
+    text.parse()
+        .map_err(|_| DurationParseError::InvalidNumber(text.to_string()))
+}
+
+fn unit_seconds(unit: &str) -> Result<f64, DurationParseError> {
+    match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" => Ok(1.0),
+        "m" | "min" | "mins" | "minute" | "minutes" => Ok(60.0),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Ok(3600.0),
+        "d" | "day" | "days" => Ok(86400.0),
+        _ => Err(DurationParseError::UnknownUnit(unit.to_string())),
+    }
+}
+
+/// `mm:ss` or `h:mm:ss`
+fn parse_clock(text: &str) -> Result<f64, DurationParseError> {
+    let parts: Vec<&str> = text.split(':').map(str::trim).collect();
+    if parts.len() > 3 {
+        return Err(DurationParseError::InvalidFormat(text.to_string()));
+    }
+
+    let mut secs = 0.0;
+    for (index, part) in parts.iter().enumerate() {
+        let value = parse_number(part)?;
+        if index > 0 && value >= 60.0 {
+            return Err(DurationParseError::FieldOutOfRange(part.to_string()));
+        }
+        secs = secs * 60.0 + value;
+    }
+    Ok(secs)
+}
+
+/// ISO 8601 durations limited to days, hours, minutes and seconds
+fn parse_iso8601(text: &str) -> Result<f64, DurationParseError> {
+    let invalid = || DurationParseError::InvalidFormat(text.to_string());
+    let body = text.strip_prefix('p').ok_or_else(invalid)?;
+    let (date_part, time_part) = match body.split_once('t') {
+        Some((date, time)) => (date, Some(time)),
+        None => (body, None),
+    };
+
+    let mut secs = 0.0;
+    let mut any = false;
+    for (part, units) in [(date_part, "d"), (time_part.unwrap_or(""), "hms")] {
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' || c == ',' {
+                number.push(if c == ',' { '.' } else { c });
+            } else if units.contains(c) && !number.is_empty() {
+                secs += parse_number(&number)? * unit_seconds(&c.to_string())?;
+                number.clear();
+                any = true;
+            } else {
+                return Err(invalid());
+            }
+        }
+        if !number.is_empty() {
+            return Err(invalid());
+        }
+    }
+
+    if !any || time_part == Some("") {
+        return Err(invalid());
+    }
+    Ok(secs)
+}
+
+/// Number/unit pairs such as "1h 15m", "90s" or "2 hours and 5 minutes"
+fn parse_units(text: &str) -> Result<f64, DurationParseError> {
+    // Split "1h15m" into tokens at every digit/letter boundary
+    let mut tokens: Vec<String> = Vec::new();
+    for word in text.split(|c: char| c.is_whitespace() || c == ',') {
+        let mut current = String::new();
+        for c in word.chars() {
+            let is_number = c.is_ascii_digit() || c == '.';
+            let boundary = current
+                .chars()
+                .last()
+                .is_some_and(|last| (last.is_ascii_digit() || last == '.') != is_number);
+            if boundary {
+                tokens.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+    }
+    tokens.retain(|token| token != "and");
+
+    let mut secs = 0.0;
+    let mut iter = tokens.iter().peekable();
+    while let Some(token) = iter.next() {
+        let value = match token.as_str() {
+            "a" | "an" => 1.0,
+            _ if token.starts_with(|c: char| c.is_ascii_digit() || c == '.') => parse_number(token)?,
+            _ => return Err(DurationParseError::InvalidFormat(text.to_string())),
+        };
+        let unit = match iter.peek() {
+            Some(next) if !next.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+                unit_seconds(iter.next().map(String::as_str).unwrap_or_default())?
+            }
+            // A lone bare number means minutes
+            None if tokens.len() == 1 => 60.0,
+            _ => return Err(DurationParseError::InvalidFormat(text.to_string())),
+        };
+        secs += value * unit;
+    }
+    Ok(secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(text: &str) -> u64 {
+        parse_duration(text).unwrap().as_secs()
+    }
+
+    #[test]
+    fn test_clock_formats() {
+        assert_eq!(secs("05:00"), 300);
+        assert_eq!(secs("1:30:00"), 5400);
+        assert_eq!(secs("120:00"), 7200);
+        assert_eq!(
+            parse_duration("5:75"),
+            Err(DurationParseError::FieldOutOfRange("75".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unit_formats() {
+        assert_eq!(secs("90s"), 90);
+        assert_eq!(secs("25m"), 1500);
+        assert_eq!(secs("1h 15m"), 4500);
+        assert_eq!(secs("1h15m30s"), 4530);
+        assert_eq!(secs("1.5h"), 5400);
+        assert_eq!(secs("2 hours and 5 minutes"), 7500);
+        assert_eq!(secs("25"), 1500);
+        assert!(matches!(parse_duration("5 fortnights"), Err(DurationParseError::UnknownUnit(_))));
+        assert!(matches!(parse_duration("5 10m"), Err(DurationParseError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_iso8601_and_phrases() {
+        assert_eq!(secs("PT45M"), 2700);
+        assert_eq!(secs("pt1h30m"), 5400);
+        assert_eq!(secs("P1DT2H"), 93600);
+        assert_eq!(secs("in 10 minutes"), 600);
+        assert_eq!(secs("In an hour"), 3600);
+        assert!(parse_duration("PT").is_err());
+        assert!(parse_duration("P5X").is_err());
+        assert_eq!(parse_duration("  "), Err(DurationParseError::Empty));
+        assert!(matches!(parse_duration("9999999h"), Err(DurationParseError::TooLarge(_))));
+    }
+
+    /// Deterministic xorshift generator so property tests need no extra crates
+    fn pseudo_random(seed: &mut u64) -> u64 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        *seed
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        let mut seed = 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..10_000 {
+            let duration = Duration::from_secs(pseudo_random(&mut seed) % (48 * 60 * 60));
+            let text = format_duration(duration);
+            assert_eq!(parse_duration(&text), Ok(duration), "round trip of {}", text);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_input_never_panics() {
+        const ALPHABET: &[u8] = b"0123456789.:, hmsdPTinaour";
+        let mut seed = 0x2545_F491_4F6C_DD1D;
+        for _ in 0..10_000 {
+            let len = pseudo_random(&mut seed) % 12;
+            let text: String = (0..len)
+                .map(|_| ALPHABET[(pseudo_random(&mut seed) % ALPHABET.len() as u64) as usize] as char)
+                .collect();
+            // Any input yields either a bounded duration or an error
+            if let Ok(duration) = parse_duration(&text) {
+                assert!(duration.as_secs_f64() <= MAX_PARSED_SECS, "{}", text);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,175 @@
+// Timer event bus: typed events delivered to any number of subscribers
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::models::config::WarningAction;
+use crate::models::timer::{TimerNotifications, TimerState};
+use crate::models::timer_collection::TimerId;
+
+/// Which timer an event concerns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerSource {
+    Main,
+    Named(TimerId),
+}
+
+/// What happened to the timer
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimerEventKind {
+    Started { duration: Option<Duration> }, // None for a stopwatch
+    Paused,
+    Resumed,
+    Reset,
+    Finished,                               // Countdown reached zero and nothing follows it
+    PhaseChanged { label: String, automatic: bool }, // Pomodoro phase or sequence step began
+    TimeAdjusted { remaining: Duration },
+    Warning { remaining: Duration, actions: Vec<WarningAction> }, // A pre-finish threshold was crossed
+    Ringing { repeats: u32 },               // Finished alarm repeating its cue until acknowledged
+    Acknowledged,                           // Ringing stopped by the user
+    RingTimedOut,                           // Ringing stopped after the maximum ring duration
+    Snoozed { duration: Duration },         // Ringing stopped and a short countdown restarted
+}
+
+/// Event published on the bus
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimerEvent {
+    pub source: TimerSource,
+    pub kind: TimerEventKind,
+    pub name: Option<String>, // Name of a named timer; None for the main timer
+}
+
+impl TimerEvent {
+    /// Event without a timer name
+    pub fn new(source: TimerSource, kind: TimerEventKind) -> Self {
+        Self { source, kind, name: None }
+    }
+}
+
+/// Handle returned by `EventBus::subscribe`, used to unsubscribe
+pub type SubscriptionId = u32;
+
+/// Dispatches timer events to registered subscribers in registration order
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<(SubscriptionId, Box<dyn TimerNotifications + Send>)>,
+    next_id: SubscriptionId,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a subscriber for all future events
+    pub fn subscribe(&mut self, subscriber: Box<dyn TimerNotifications + Send>) -> SubscriptionId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.subscribers.push((id, subscriber));
+        id
+    }
+
+    /// Remove a subscriber, returning true if it was registered
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.subscribers.len();
+        self.subscribers.retain(|(existing, _)| *existing != id);
+        self.subscribers.len() != before
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Deliver an event to every subscriber
+    pub fn publish(&self, event: &TimerEvent) {
+        for (_, subscriber) in &self.subscribers {
+            subscriber.on_event(event);
+        }
+    }
+
+    /// Report a change of the main timer's state
+    pub fn publish_state_change(&self, old_state: &TimerState, new_state: &TimerState) {
+        for (_, subscriber) in &self.subscribers {
+            subscriber.on_state_changed(old_state, new_state);
+        }
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
+
+/// Subscriber that records every event, for history views and tests
+#[derive(Debug, Clone, Default)]
+pub struct EventLog {
+    events: Arc<Mutex<Vec<TimerEvent>>>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events received so far, oldest first
+    pub fn events(&self) -> Vec<TimerEvent> {
+        self.events.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Kinds of the events received so far, oldest first
+    pub fn kinds(&self) -> Vec<TimerEventKind> {
+        self.events().into_iter().map(|event| event.kind).collect()
+    }
+
+    pub fn clear(&self) {
+        self.events.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+impl TimerNotifications for EventLog {
+    fn on_event(&self, event: &TimerEvent) {
+        self.events.lock().unwrap_or_else(|e| e.into_inner()).push(event.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Default)]
+    struct FinishCounter(Arc<AtomicU32>);
+
+    impl TimerNotifications for FinishCounter {
+        fn on_timer_finished(&self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn event(kind: TimerEventKind) -> TimerEvent {
+        TimerEvent::new(TimerSource::Main, kind)
+    }
+
+    #[test]
+    fn test_publish_reaches_every_subscriber() {
+        let mut bus = EventBus::new();
+        let log = EventLog::new();
+        let finished = Arc::new(AtomicU32::new(0));
+        bus.subscribe(Box::new(log.clone()));
+        let counter = bus.subscribe(Box::new(FinishCounter(finished.clone())));
+
+        bus.publish(&event(TimerEventKind::Paused));
+        bus.publish(&event(TimerEventKind::Finished));
+        assert_eq!(log.kinds(), vec![TimerEventKind::Paused, TimerEventKind::Finished]);
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+
+        // Unsubscribed handlers stop receiving events
+        assert!(bus.unsubscribe(counter));
+        assert!(!bus.unsubscribe(counter));
+        bus.publish(&event(TimerEventKind::Finished));
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+        assert_eq!(bus.subscriber_count(), 1);
+    }
+}
\ No newline at end of file
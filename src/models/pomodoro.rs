@@ -0,0 +1,141 @@
+// Pomodoro cycle engine built on top of the countdown timer
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::models::config::PomodoroConfig;
+
+/// Phase of a Pomodoro cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl PomodoroPhase {
+    /// Human-readable label shown in the widget
+    pub fn label(&self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "Work",
+            PomodoroPhase::ShortBreak => "Short Break",
+            PomodoroPhase::LongBreak => "Long Break",
+        }
+    }
+
+    /// Check if the phase is a break of either length
+    pub fn is_break(&self) -> bool {
+        !matches!(self, PomodoroPhase::Work)
+    }
+}
+
+/// Running Pomodoro session tracking the current phase and completed cycles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PomodoroSession {
+    pub config: PomodoroConfig,
+    pub phase: PomodoroPhase,
+    pub completed_work_sessions: u32,
+    pub completed_phases: u32, // Total phases finished, including breaks
+}
+
+impl PomodoroSession {
+    /// Create a session starting with a work phase
+    pub fn new(config: PomodoroConfig) -> Self {
+        Self {
+            config,
+            phase: PomodoroPhase::Work,
+            completed_work_sessions: 0,
+            completed_phases: 0,
+        }
+    }
+
+    /// Duration of the given phase according to the session configuration
+    pub fn phase_duration(&self, phase: PomodoroPhase) -> Duration {
+        let minutes = match phase {
+            PomodoroPhase::Work => self.config.work_minutes,
+            PomodoroPhase::ShortBreak => self.config.short_break_minutes,
+            PomodoroPhase::LongBreak => self.config.long_break_minutes,
+        };
+        Duration::from_secs(minutes as u64 * 60)
+    }
+
+    /// Duration of the current phase
+    pub fn current_duration(&self) -> Duration {
+        self.phase_duration(self.phase)
+    }
+
+    /// 1-based number of the work cycle in progress (or just completed, during a break)
+    pub fn cycle(&self) -> u32 {
+        if self.phase.is_break() {
+            self.completed_work_sessions
+        } else {
+            self.completed_work_sessions + 1
+        }
+    }
+
+    /// Phase that follows the current one
+    pub fn next_phase(&self) -> PomodoroPhase {
+        match self.phase {
+            PomodoroPhase::Work => {
+                let every = self.config.long_break_every.max(1);
+                if (self.completed_work_sessions + 1).is_multiple_of(every) {
+                    PomodoroPhase::LongBreak
+                } else {
+                    PomodoroPhase::ShortBreak
+                }
+            }
+            PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => PomodoroPhase::Work,
+        }
+    }
+
+    /// Finish the current phase and move to the next one, returning it
+    pub fn advance(&mut self) -> PomodoroPhase {
+        let next = self.next_phase();
+        if self.phase == PomodoroPhase::Work {
+            self.completed_work_sessions += 1;
+        }
+        self.completed_phases += 1;
+        self.phase = next;
+        next
+    }
+
+    /// Label for the widget, e.g. "Work 2/4"
+    pub fn display_label(&self) -> String {
+        let every = self.config.long_break_every.max(1);
+        let position = (self.cycle().max(1) - 1) % every + 1;
+        format!("{} {}/{}", self.phase.label(), position, every)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_sequence_with_long_break() {
+        let mut session = PomodoroSession::new(PomodoroConfig::default());
+        let mut phases = vec![session.phase];
+        for _ in 0..8 {
+            phases.push(session.advance());
+        }
+
+        use PomodoroPhase::*;
+        assert_eq!(
+            phases,
+            vec![Work, ShortBreak, Work, ShortBreak, Work, ShortBreak, Work, LongBreak, Work]
+        );
+        assert_eq!(session.completed_work_sessions, 4);
+        assert_eq!(session.completed_phases, 8);
+        assert_eq!(session.cycle(), 5);
+    }
+
+    #[test]
+    fn test_phase_durations_and_labels() {
+        let mut session = PomodoroSession::new(PomodoroConfig::default());
+        assert_eq!(session.current_duration(), Duration::from_secs(25 * 60));
+        assert_eq!(session.display_label(), "Work 1/4");
+
+        session.advance();
+        assert_eq!(session.current_duration(), Duration::from_secs(5 * 60));
+        assert_eq!(session.display_label(), "Short Break 1/4");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+// Alarm that keeps ringing after a countdown finishes until it is acknowledged
+use std::time::{Duration, Instant};
+
+use crate::models::events::TimerSource;
+
+/// Fallback time between repeated cues when the configured interval is invalid
+pub const RING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Fallback limit on how long an unacknowledged alarm rings
+pub const MAX_RING_DURATION: Duration = Duration::from_secs(5 * 60);
+
+/// Fallback snooze countdown
+pub const SNOOZE_DURATION: Duration = Duration::from_secs(5 * 60);
+
+/// What a ringing alarm should do at the current time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingStep {
+    Quiet,    // Between cues
+    Repeat,   // Give the sound/flash cue again
+    TimedOut, // Rang for the maximum duration without being acknowledged
+}
+
+/// Repeating alarm for a finished timer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ringing {
+    pub source: TimerSource,
+    pub started_at: Instant,
+    pub last_cue: Instant,
+    pub repeats: u32, // Cues given after the initial one
+}
+
+impl Ringing {
+    pub fn new(source: TimerSource, now: Instant) -> Self {
+        Self {
+            source,
+            started_at: now,
+            last_cue: now,
+            repeats: 0,
+        }
+    }
+
+    /// Advance to `now`, repeating every `interval` until `max_duration` has passed
+    pub fn step(&mut self, now: Instant, interval: Duration, max_duration: Duration) -> RingStep {
+        if now.saturating_duration_since(self.started_at) >= max_duration {
+            return RingStep::TimedOut;
+        }
+        if now.saturating_duration_since(self.last_cue) >= interval {
+            self.last_cue = now;
+            self.repeats += 1;
+            return RingStep::Repeat;
+        }
+        RingStep::Quiet
+    }
+
+    /// How long the alarm has been ringing
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.started_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeats_until_timed_out() {
+        let start = Instant::now();
+        let mut ringing = Ringing::new(TimerSource::Main, start);
+        let interval = Duration::from_secs(10);
+        let max = Duration::from_secs(30);
+
+        assert_eq!(ringing.step(start + Duration::from_secs(5), interval, max), RingStep::Quiet);
+        assert_eq!(ringing.step(start + Duration::from_secs(10), interval, max), RingStep::Repeat);
+        assert_eq!(ringing.step(start + Duration::from_secs(15), interval, max), RingStep::Quiet);
+        assert_eq!(ringing.step(start + Duration::from_secs(21), interval, max), RingStep::Repeat);
+        assert_eq!(ringing.step(start + Duration::from_secs(30), interval, max), RingStep::TimedOut);
+        assert_eq!(ringing.repeats, 2);
+        assert_eq!(ringing.elapsed(start + Duration::from_secs(30)), max);
+    }
+}
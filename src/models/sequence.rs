@@ -0,0 +1,235 @@
+// Multi-step timer sequences (interval programs)
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::models::config::ValidationError;
+
+/// Maximum length of a single step, matching the timer's 24 hour cap
+const MAX_STEP_SECS: u64 = 24 * 60 * 60;
+
+/// A single labelled step of a sequence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SequenceStep {
+    pub label: String,      // e.g., "Warm-up"
+    pub duration_secs: u64,
+}
+
+/// Ordered program of steps, optionally repeated
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimerSequence {
+    pub name: String,
+    pub steps: Vec<SequenceStep>,
+    pub repeat: u32, // Number of rounds through all steps (>= 1)
+}
+
+/// Transition reported when a sequence moves between steps
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SequenceEvent {
+    StepStarted { index: usize, round: u32, label: String },
+    Completed,
+}
+
+/// Progress through a running sequence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequenceRun {
+    pub sequence: TimerSequence,
+    pub step_index: usize,
+    pub round: u32,        // 0-based round
+    pub transitions: u32,  // Number of step transitions so far
+    pub last_event: Option<SequenceEvent>,
+}
+
+impl SequenceStep {
+    pub fn new(label: &str, duration: Duration) -> Self {
+        Self {
+            label: label.to_string(),
+            duration_secs: duration.as_secs(),
+        }
+    }
+
+    /// Step length as a duration
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.duration_secs)
+    }
+}
+
+impl TimerSequence {
+    pub fn new(name: &str, steps: Vec<SequenceStep>, repeat: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            steps,
+            repeat,
+        }
+    }
+
+    /// Total time of all rounds
+    pub fn total_duration(&self) -> Duration {
+        let round: Duration = self.steps.iter().map(SequenceStep::duration).sum();
+        round * self.repeat.max(1)
+    }
+
+    /// Validate the sequence; `index` identifies it within the configuration
+    pub fn validate(&self, index: usize) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if self.steps.is_empty() {
+            errors.push(ValidationError::InvalidSequence(format!(
+                "sequence '{}' has no steps",
+                self.name
+            )));
+        }
+
+        if self.repeat == 0 {
+            errors.push(ValidationError::InvalidSequence(format!(
+                "sequence '{}' must repeat at least once",
+                self.name
+            )));
+        }
+
+        for (step_index, step) in self.steps.iter().enumerate() {
+            if step.duration_secs == 0 || step.duration_secs > MAX_STEP_SECS {
+                errors.push(ValidationError::InvalidDuration(format!(
+                    "sequences[{}].steps[{}].duration_secs",
+                    index, step_index
+                )));
+            }
+        }
+
+        errors
+    }
+}
+
+impl SequenceRun {
+    /// Begin a sequence at its first step
+    pub fn new(sequence: TimerSequence) -> Self {
+        Self {
+            sequence,
+            step_index: 0,
+            round: 0,
+            transitions: 0,
+            last_event: None,
+        }
+    }
+
+    /// Step currently being timed
+    pub fn current_step(&self) -> &SequenceStep {
+        &self.sequence.steps[self.step_index]
+    }
+
+    /// Move to the next step, returning None once every round is complete
+    pub fn advance(&mut self) -> Option<&SequenceStep> {
+        if self.step_index + 1 < self.sequence.steps.len() {
+            self.step_index += 1;
+        } else if self.round + 1 < self.sequence.repeat {
+            self.round += 1;
+            self.step_index = 0;
+        } else {
+            self.record(SequenceEvent::Completed);
+            return None;
+        }
+
+        self.record_step_started();
+        Some(self.current_step())
+    }
+
+    /// Move back one step (crossing into the previous round if needed)
+    pub fn previous(&mut self) -> &SequenceStep {
+        if self.step_index > 0 {
+            self.step_index -= 1;
+        } else if self.round > 0 {
+            self.round -= 1;
+            self.step_index = self.sequence.steps.len() - 1;
+        }
+
+        self.record_step_started();
+        self.current_step()
+    }
+
+    /// Label for the widget, e.g. "Work 2/3 · round 1/2"
+    pub fn display_label(&self) -> String {
+        let mut label = format!(
+            "{} {}/{}",
+            self.current_step().label,
+            self.step_index + 1,
+            self.sequence.steps.len()
+        );
+        if self.sequence.repeat > 1 {
+            label.push_str(&format!(" · round {}/{}", self.round + 1, self.sequence.repeat));
+        }
+        label
+    }
+
+    fn record_step_started(&mut self) {
+        let event = SequenceEvent::StepStarted {
+            index: self.step_index,
+            round: self.round,
+            label: self.current_step().label.clone(),
+        };
+        self.record(event);
+    }
+
+    fn record(&mut self, event: SequenceEvent) {
+        self.transitions += 1;
+        self.last_event = Some(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_sequence(repeat: u32) -> TimerSequence {
+        TimerSequence::new(
+            "Deep work",
+            vec![
+                SequenceStep::new("Warm-up", Duration::from_secs(5 * 60)),
+                SequenceStep::new("Work", Duration::from_secs(45 * 60)),
+                SequenceStep::new("Review", Duration::from_secs(10 * 60)),
+            ],
+            repeat,
+        )
+    }
+
+    #[test]
+    fn test_advance_through_rounds() {
+        let mut run = SequenceRun::new(sample_sequence(2));
+        let mut labels = vec![run.current_step().label.clone()];
+        while let Some(step) = run.advance() {
+            labels.push(step.label.clone());
+        }
+
+        assert_eq!(labels, vec!["Warm-up", "Work", "Review", "Warm-up", "Work", "Review"]);
+        assert_eq!(run.last_event, Some(SequenceEvent::Completed));
+        assert_eq!(run.transitions, 6);
+    }
+
+    #[test]
+    fn test_previous_crosses_rounds() {
+        let mut run = SequenceRun::new(sample_sequence(2));
+        for _ in 0..3 {
+            run.advance();
+        }
+        assert_eq!((run.round, run.step_index), (1, 0));
+
+        assert_eq!(run.previous().label, "Review");
+        assert_eq!((run.round, run.step_index), (0, 2));
+
+        // Previous at the very first step stays put
+        let mut fresh = SequenceRun::new(sample_sequence(1));
+        assert_eq!(fresh.previous().label, "Warm-up");
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(sample_sequence(1).validate(0).is_empty());
+        assert_eq!(sample_sequence(1).total_duration(), Duration::from_secs(60 * 60));
+
+        let mut invalid = sample_sequence(0);
+        invalid.steps[1].duration_secs = 0;
+        let errors = invalid.validate(3);
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains(&ValidationError::InvalidDuration(
+            "sequences[3].steps[1].duration_secs".to_string()
+        )));
+    }
+}
\ No newline at end of file
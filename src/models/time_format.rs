@@ -0,0 +1,246 @@
+// Display format templates for remaining/elapsed time, e.g. "{hh}:{mm}:{ss}"
+use std::time::{Duration, SystemTime};
+
+use crate::models::alarm::format_alarm_target;
+
+/// Default template: hours collapse away below one hour, giving "05:00" or "2:00:00"
+pub const DEFAULT_TIME_FORMAT: &str = "{h}:{mm}:{ss}";
+
+/// Template used by the inline `mm:ss` editor
+pub const EDIT_TIME_FORMAT: &str = "{mm}:{ss}";
+
+/// A placeholder within a format template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatToken {
+    Hours { padded: bool },
+    Minutes { padded: bool },   // Carries the hours when no hours field is shown
+    Seconds { padded: bool },
+    Tenths,
+    TotalMinutes,               // Whole minutes, ignoring hours
+    Percent,                    // Percent complete, empty when unknown
+    Target,                     // Clock time the countdown ends at
+    Phase,                      // Pomodoro phase or sequence step name
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Token(FormatToken),
+}
+
+/// Values available to a template
+#[derive(Debug, Clone, Default)]
+pub struct FormatContext<'a> {
+    pub duration: Duration,          // Time to show (remaining, elapsed or overtime)
+    pub progress: Option<f32>,       // 0.0 to 1.0 of a countdown completed
+    pub target: Option<SystemTime>,  // Wall-clock finish time
+    pub phase: Option<&'a str>,
+}
+
+/// Parsed display format template
+///
+/// Tokens: `{h}`/`{hh}` hours, `{m}`/`{mm}` minutes, `{s}`/`{ss}` seconds,
+/// `{t}` tenths, `{total_minutes}`, `{percent}`, `{target}` and `{phase}`.
+/// Doubled letters are zero-padded to two digits; `{{` and `}}` are literal braces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeFormat {
+    segments: Vec<Segment>,
+    pub collapse_leading_zeros: bool, // Drop a zero hours field and its separator
+}
+
+impl TimeFormat {
+    /// Parse a template, returning the offending text if it is malformed
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    let mut closed = false;
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        name.push(c);
+                    }
+                    let token = Self::token(&name)
+                        .filter(|_| closed)
+                        .ok_or_else(|| format!("{{{}", name))?;
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Token(token));
+                }
+                '}' => return Err("}".to_string()),
+                _ => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self {
+            segments,
+            collapse_leading_zeros: true,
+        })
+    }
+
+    /// Parse a template, falling back to the default one if it is malformed
+    pub fn parse_or_default(template: &str) -> Self {
+        Self::parse(template)
+            .or_else(|_| Self::parse(DEFAULT_TIME_FORMAT))
+            .expect("default time format is valid")
+    }
+
+    fn token(name: &str) -> Option<FormatToken> {
+        Some(match name {
+            "h" => FormatToken::Hours { padded: false },
+            "hh" => FormatToken::Hours { padded: true },
+            "m" => FormatToken::Minutes { padded: false },
+            "mm" => FormatToken::Minutes { padded: true },
+            "s" => FormatToken::Seconds { padded: false },
+            "ss" => FormatToken::Seconds { padded: true },
+            "t" => FormatToken::Tenths,
+            "total_minutes" => FormatToken::TotalMinutes,
+            "percent" => FormatToken::Percent,
+            "target" => FormatToken::Target,
+            "phase" => FormatToken::Phase,
+            _ => return None,
+        })
+    }
+
+    /// Render the template
+    pub fn format(&self, context: &FormatContext) -> String {
+        let total_secs = context.duration.as_secs();
+        let hours = total_secs / 3600;
+
+        let mut segments: Vec<&Segment> = self.segments.iter().collect();
+
+        // Collapse a leading "0:" while keeping everything before it
+        let first_unit = segments.iter().position(|s| {
+            matches!(
+                s,
+                Segment::Token(FormatToken::Hours { .. } | FormatToken::Minutes { .. } | FormatToken::Seconds { .. })
+            )
+        });
+        if let Some(index) = first_unit {
+            let is_hours = matches!(segments[index], Segment::Token(FormatToken::Hours { .. }));
+            if self.collapse_leading_zeros && hours == 0 && is_hours {
+                let end = match segments.get(index + 1) {
+                    Some(Segment::Literal(_)) => index + 2,
+                    _ => index + 1,
+                };
+                segments.drain(index..end);
+            }
+        }
+
+        let shows_hours = segments
+            .iter()
+            .any(|s| matches!(s, Segment::Token(FormatToken::Hours { .. })));
+        let minutes = if shows_hours { total_secs % 3600 / 60 } else { total_secs / 60 };
+
+        let mut output = String::new();
+        for segment in segments {
+            match segment {
+                Segment::Literal(text) => output.push_str(text),
+                Segment::Token(token) => output.push_str(&match *token {
+                    FormatToken::Hours { padded } => Self::number(hours, padded),
+                    FormatToken::Minutes { padded } => Self::number(minutes, padded),
+                    FormatToken::Seconds { padded } => Self::number(total_secs % 60, padded),
+                    FormatToken::Tenths => (context.duration.subsec_millis() / 100).to_string(),
+                    FormatToken::TotalMinutes => (total_secs / 60).to_string(),
+                    FormatToken::Percent => context
+                        .progress
+                        // Small epsilon so 0.2 - rounding error still reads as 20%
+                        .map(|p| format!("{:.0}", (p.clamp(0.0, 1.0) * 100.0 + 1e-3).floor()))
+                        .unwrap_or_default(),
+                    FormatToken::Target => context.target.map(format_alarm_target).unwrap_or_default(),
+                    FormatToken::Phase => context.phase.unwrap_or_default().to_string(),
+                }),
+            }
+        }
+        output
+    }
+
+    fn number(value: u64, padded: bool) -> String {
+        if padded {
+            format!("{:02}", value)
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self::parse_or_default(DEFAULT_TIME_FORMAT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, secs: f64) -> String {
+        let context = FormatContext {
+            duration: Duration::from_secs_f64(secs),
+            ..Default::default()
+        };
+        TimeFormat::parse(template).unwrap().format(&context)
+    }
+
+    #[test]
+    fn test_default_format_collapses_hours() {
+        assert_eq!(render(DEFAULT_TIME_FORMAT, 300.0), "05:00");
+        assert_eq!(render(DEFAULT_TIME_FORMAT, 7200.0), "2:00:00");
+        assert_eq!(render(DEFAULT_TIME_FORMAT, 5025.0), "1:23:45");
+    }
+
+    #[test]
+    fn test_minutes_carry_hours_when_not_shown() {
+        assert_eq!(render(EDIT_TIME_FORMAT, 7200.0), "120:00");
+
+        let mut format = TimeFormat::parse("{hh}:{mm}:{ss}").unwrap();
+        format.collapse_leading_zeros = false;
+        let context = FormatContext {
+            duration: Duration::from_secs(65),
+            ..Default::default()
+        };
+        assert_eq!(format.format(&context), "00:01:05");
+    }
+
+    #[test]
+    fn test_tenths_percent_and_phase() {
+        assert_eq!(render("{m}:{ss}.{t}", 4.96), "0:04.9");
+        assert_eq!(render("{total_minutes} min", 5025.0), "83 min");
+
+        let context = FormatContext {
+            duration: Duration::from_secs(600),
+            progress: Some(0.6),
+            phase: Some("Work"),
+            ..Default::default()
+        };
+        let format = TimeFormat::parse("{phase} {mm}:{ss} ({percent}%) {{live}}").unwrap();
+        assert_eq!(format.format(&context), "Work 10:00 (60%) {live}");
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        assert_eq!(TimeFormat::parse("{mm}:{sec}"), Err("{sec".to_string()));
+        assert_eq!(TimeFormat::parse("{mm"), Err("{mm".to_string()));
+        assert!(TimeFormat::parse("mm}").is_err());
+        assert_eq!(TimeFormat::parse_or_default("{bad}"), TimeFormat::default());
+    }
+}
\ No newline at end of file
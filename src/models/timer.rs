@@ -1,5 +1,19 @@
 // Timer model and state machine implementation
-use std::time::{Duration, Instant};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::models::clock::{SharedClock, SystemClock};
+use crate::models::events::{TimerEvent, TimerEventKind};
+use crate::models::timer_snapshot::{from_epoch_ms, to_epoch_ms, to_ms, SnapshotState, TimerSnapshot};
+
+/// Wall-clock drift beyond which a running timer assumes the system was suspended
+pub const SUSPEND_DETECTION_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// Furthest ahead an alarm may be set
+pub const MAX_ALARM_LEAD: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Maximum number of laps kept in a timer's history
+pub const MAX_LAP_HISTORY: usize = 100;
 
 /// Timer state enumeration representing all possible timer states
 #[derive(Debug, Clone, PartialEq)]
@@ -8,11 +22,52 @@ pub enum TimerState {
     Running { 
         started_at: Instant,
         remaining_duration: Duration,
+        deadline: SystemTime, // Wall-clock finish time, survives system sleep
     },
     Paused {
         remaining_duration: Duration,
     },
     Finished,
+    StopwatchRunning {
+        started_at: Instant,
+        elapsed_before: Duration, // Time accumulated before the last resume
+    },
+    StopwatchPaused {
+        elapsed: Duration,
+    },
+    Overtime {
+        finished_at: Instant, // When the countdown reached zero
+    },
+}
+
+impl TimerState {
+    /// Check if the state belongs to count-up (stopwatch) mode
+    pub fn is_stopwatch(&self) -> bool {
+        matches!(self, TimerState::StopwatchRunning { .. } | TimerState::StopwatchPaused { .. })
+    }
+    
+    /// Check if time is currently advancing, in either mode
+    pub fn is_active(&self) -> bool {
+        matches!(self, TimerState::Running { .. } | TimerState::StopwatchRunning { .. })
+    }
+    
+    /// Check if the countdown has reached zero, whether or not overtime is counting
+    pub fn is_finished(&self) -> bool {
+        matches!(self, TimerState::Finished | TimerState::Overtime { .. })
+    }
+    
+    /// Check if the timer is paused, in either mode
+    pub fn is_paused(&self) -> bool {
+        matches!(self, TimerState::Paused { .. } | TimerState::StopwatchPaused { .. })
+    }
+}
+
+/// A recorded lap (split) of a running stopwatch or countdown
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lap {
+    pub index: usize,    // 1-based lap number, keeps counting past evicted history
+    pub split: Duration, // Total elapsed time when the lap was recorded
+    pub lap: Duration,   // Time since the previous lap (or since start)
 }
 
 /// Timer error types
@@ -47,27 +102,42 @@ pub trait TimerControl {
     /// Reset timer to stopped state
     fn reset(&mut self);
     
+    /// Extend a running or paused countdown
+    fn add_time(&mut self, amount: Duration) -> Result<(), TimerError>;
+    
+    /// Shorten a running or paused countdown, finishing it if nothing remains
+    fn subtract_time(&mut self, amount: Duration) -> Result<(), TimerError>;
+    
     /// Get current timer state
     fn state(&self) -> &TimerState;
     
-    /// Get remaining time (if running or paused)
+    /// Get remaining time (if a countdown is running or paused)
     fn remaining_time(&self) -> Option<Duration>;
     
     /// Check if timer has finished (countdown reached zero)
     fn is_finished(&self) -> bool;
     
-    /// Update timer state based on elapsed time
+    /// Update timer state based on time elapsed on the timer's clock
     /// Returns true if state changed (requires UI update)
     fn tick(&mut self) -> bool;
 }
 
-/// Timer notification interface
+/// Timer notification interface, implemented by event bus subscribers
+///
+/// Every method has an empty default so subscribers implement only what they need.
 pub trait TimerNotifications {
+    /// Called for every event published on the bus
+    fn on_event(&self, event: &TimerEvent) {
+        if event.kind == TimerEventKind::Finished {
+            self.on_timer_finished();
+        }
+    }
+    
     /// Called when timer finishes countdown
-    fn on_timer_finished(&self);
+    fn on_timer_finished(&self) {}
     
     /// Called when timer state changes
-    fn on_state_changed(&self, old_state: &TimerState, new_state: &TimerState);
+    fn on_state_changed(&self, _old_state: &TimerState, _new_state: &TimerState) {}
 }
 
 /// Main timer implementation
@@ -76,20 +146,42 @@ pub struct Timer {
     pub state: TimerState,
     pub original_duration: Duration,
     pub completion_time: Option<Instant>,
+    pub suspended_for: Duration, // Time missed while the system was suspended
+    pub finished_while_suspended: bool,
+    pub laps: VecDeque<Lap>, // Bounded to MAX_LAP_HISTORY, oldest first
+    pub overtime_enabled: bool, // Keep counting past zero until dismissed
+    pub alarm_target: Option<SystemTime>, // Wall-clock time an alarm countdown ends at
+    clock: SharedClock,
 }
 
 impl Timer {
     /// Create a new timer in stopped state
     pub fn new() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+    
+    /// Create a new timer driven by the given clock
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             state: TimerState::Stopped,
             original_duration: Duration::from_secs(0),
             completion_time: None,
+            suspended_for: Duration::ZERO,
+            finished_while_suspended: false,
+            laps: VecDeque::new(),
+            overtime_enabled: false,
+            alarm_target: None,
+            clock,
         }
     }
     
+    /// Clock used to measure elapsed time
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+    
     /// Validate that a duration is within acceptable bounds
-    fn validate_duration(duration: Duration) -> Result<(), TimerError> {
+    pub fn validate_duration(duration: Duration) -> Result<(), TimerError> {
         if duration.is_zero() {
             return Err(TimerError::InvalidDuration(
                 "Duration must be greater than zero".to_string()
@@ -106,15 +198,273 @@ impl Timer {
         Ok(())
     }
     
+    /// Replace the remaining time of a running or paused countdown
+    fn set_remaining(&mut self, remaining: Duration) {
+        if remaining.is_zero() {
+            self.finish(self.clock.now());
+            return;
+        }
+        
+        self.state = match self.state {
+            TimerState::Running { .. } => self.running_state(remaining),
+            _ => TimerState::Paused { remaining_duration: remaining },
+        };
+    }
+    
     /// Helper to calculate remaining time for a running timer
-    fn calculate_remaining_time(started_at: Instant, original_duration: Duration) -> Duration {
-        let elapsed = started_at.elapsed();
+    fn calculate_remaining_time(&self, started_at: Instant, original_duration: Duration) -> Duration {
+        let elapsed = self.clock.elapsed_since(started_at);
         if elapsed >= original_duration {
             Duration::ZERO
         } else {
             original_duration - elapsed
         }
     }
+    
+    /// Helper to calculate remaining time until a wall-clock deadline
+    fn calculate_wall_remaining_time(&self, deadline: SystemTime) -> Duration {
+        deadline
+            .duration_since(self.clock.wall_now())
+            .unwrap_or(Duration::ZERO)
+    }
+    
+    /// Build a running state anchored at the current monotonic and wall-clock time
+    fn running_state(&self, remaining_duration: Duration) -> TimerState {
+        TimerState::Running {
+            started_at: self.clock.now(),
+            remaining_duration,
+            deadline: self.clock.wall_now() + remaining_duration,
+        }
+    }
+    
+    /// Transition to finished (or overtime) state at the instant the countdown hit zero
+    fn finish(&mut self, finished_at: Instant) {
+        self.state = if self.overtime_enabled {
+            TimerState::Overtime { finished_at }
+        } else {
+            TimerState::Finished
+        };
+        self.completion_time = Some(finished_at);
+    }
+    
+    /// Time spent past zero while in overtime
+    pub fn overtime(&self) -> Option<Duration> {
+        match &self.state {
+            TimerState::Overtime { finished_at } => Some(self.clock.elapsed_since(*finished_at)),
+            _ => None,
+        }
+    }
+    
+    /// Count down to an absolute wall-clock time (alarm mode)
+    pub fn start_alarm(&mut self, target: SystemTime) -> Result<(), TimerError> {
+        let remaining = self.calculate_wall_remaining_time(target);
+        if remaining.is_zero() {
+            return Err(TimerError::InvalidDuration(
+                "Alarm time must be in the future".to_string()
+            ));
+        }
+        if remaining > MAX_ALARM_LEAD {
+            return Err(TimerError::InvalidDuration(
+                "Alarm time cannot be more than 7 days ahead".to_string()
+            ));
+        }
+        
+        match self.state {
+            TimerState::Stopped | TimerState::Finished | TimerState::Overtime { .. } => {
+                self.state = self.running_state(remaining);
+                self.original_duration = remaining;
+                self.completion_time = None;
+                self.suspended_for = Duration::ZERO;
+                self.finished_while_suspended = false;
+                self.laps.clear();
+                self.alarm_target = Some(target);
+                Ok(())
+            }
+            _ => Err(TimerError::InvalidState(
+                "Cannot start alarm: timer is already running or paused".to_string()
+            ))
+        }
+    }
+    
+    /// Check if the countdown ends at a fixed wall-clock time
+    pub fn is_alarm(&self) -> bool {
+        self.alarm_target.is_some()
+    }
+    
+    /// Re-evaluate a running alarm against the wall clock
+    ///
+    /// The wall clock is authoritative for alarms, so system clock changes and
+    /// suspends in either direction move the countdown to match the target.
+    fn tick_alarm(&mut self, target: SystemTime) -> bool {
+        let TimerState::Running { started_at, remaining_duration, .. } = self.state else {
+            return false;
+        };
+        
+        let current_remaining = self.calculate_remaining_time(started_at, remaining_duration);
+        let wall_remaining = self.calculate_wall_remaining_time(target);
+        
+        if wall_remaining.is_zero() {
+            self.finish(self.clock.now());
+            return true;
+        }
+        
+        if current_remaining.abs_diff(wall_remaining) > SUSPEND_DETECTION_THRESHOLD {
+            self.state = self.running_state(wall_remaining);
+            return true;
+        }
+        
+        false
+    }
+    
+    /// Start counting up from zero (stopwatch mode)
+    pub fn start_stopwatch(&mut self) -> Result<(), TimerError> {
+        match self.state {
+            TimerState::Stopped | TimerState::Finished | TimerState::Overtime { .. } => {
+                self.state = TimerState::StopwatchRunning {
+                    started_at: self.clock.now(),
+                    elapsed_before: Duration::ZERO,
+                };
+                self.original_duration = Duration::from_secs(0);
+                self.completion_time = None;
+                self.suspended_for = Duration::ZERO;
+                self.finished_while_suspended = false;
+                self.laps.clear();
+                self.alarm_target = None;
+                Ok(())
+            }
+            _ => Err(TimerError::InvalidState(
+                "Cannot start stopwatch: timer is already running or paused".to_string()
+            ))
+        }
+    }
+    
+    /// Get elapsed time since start, excluding paused periods
+    /// Counts up in stopwatch mode; for countdowns this is time already consumed
+    pub fn elapsed_time(&self) -> Option<Duration> {
+        match &self.state {
+            TimerState::StopwatchRunning { started_at, elapsed_before } => {
+                Some(*elapsed_before + self.clock.elapsed_since(*started_at))
+            }
+            TimerState::StopwatchPaused { elapsed } => Some(*elapsed),
+            TimerState::Running { .. } | TimerState::Paused { .. } => self
+                .remaining_time()
+                .map(|remaining| self.original_duration.saturating_sub(remaining)),
+            _ => None,
+        }
+    }
+    
+    /// Check if the timer is in count-up (stopwatch) mode
+    pub fn is_stopwatch(&self) -> bool {
+        self.state.is_stopwatch()
+    }
+    
+    /// Record a lap at the current elapsed time
+    pub fn record_lap(&mut self) -> Result<Lap, TimerError> {
+        if !self.state.is_active() {
+            return Err(TimerError::InvalidState(
+                "Cannot record lap: timer is not running".to_string()
+            ));
+        }
+        
+        let split = self.elapsed_time().unwrap_or(Duration::ZERO);
+        let (index, previous_split) = match self.laps.back() {
+            Some(last) => (last.index + 1, last.split),
+            None => (1, Duration::ZERO),
+        };
+        let lap = Lap {
+            index,
+            split,
+            lap: split.saturating_sub(previous_split),
+        };
+        
+        if self.laps.len() >= MAX_LAP_HISTORY {
+            self.laps.pop_front();
+        }
+        self.laps.push_back(lap);
+        Ok(lap)
+    }
+    
+    /// Capture the timer in a form that survives a restart
+    pub fn snapshot(&self) -> TimerSnapshot {
+        let wall_now = self.clock.wall_now();
+        let state = match &self.state {
+            TimerState::Stopped => SnapshotState::Stopped,
+            TimerState::Running { .. } => SnapshotState::Running {
+                deadline_ms: to_epoch_ms(wall_now + self.remaining_time().unwrap_or_default()),
+            },
+            TimerState::Paused { remaining_duration } => SnapshotState::Paused {
+                remaining_ms: to_ms(*remaining_duration),
+            },
+            TimerState::Finished => SnapshotState::Finished,
+            TimerState::StopwatchRunning { .. } => SnapshotState::StopwatchRunning {
+                started_ms: to_epoch_ms(wall_now - self.elapsed_time().unwrap_or_default()),
+            },
+            TimerState::StopwatchPaused { elapsed } => SnapshotState::StopwatchPaused {
+                elapsed_ms: to_ms(*elapsed),
+            },
+            TimerState::Overtime { .. } => SnapshotState::Overtime {
+                finished_ms: to_epoch_ms(wall_now - self.overtime().unwrap_or_default()),
+            },
+        };
+        
+        TimerSnapshot {
+            state,
+            original_duration_ms: to_ms(self.original_duration),
+            alarm_target_ms: self.alarm_target.map(to_epoch_ms),
+        }
+    }
+    
+    /// Restore a snapshot, catching up with the time that passed since it was taken
+    ///
+    /// A countdown whose deadline passed in the meantime finishes immediately,
+    /// back-dated to the deadline, and is flagged like a finish during suspend.
+    pub fn restore(&mut self, snapshot: &TimerSnapshot) {
+        self.reset();
+        self.original_duration = Duration::from_millis(snapshot.original_duration_ms);
+        self.alarm_target = snapshot.alarm_target_ms.map(from_epoch_ms);
+        
+        let now = self.clock.now();
+        let wall_now = self.clock.wall_now();
+        let since = |ms: u64| wall_now.duration_since(from_epoch_ms(ms)).unwrap_or(Duration::ZERO);
+        
+        self.state = match snapshot.state {
+            SnapshotState::Stopped => TimerState::Stopped,
+            SnapshotState::Running { deadline_ms } => {
+                let remaining = self.calculate_wall_remaining_time(from_epoch_ms(deadline_ms));
+                if remaining.is_zero() {
+                    let overdue = since(deadline_ms);
+                    self.finished_while_suspended = true;
+                    self.finish(now.checked_sub(overdue).unwrap_or(now));
+                    return;
+                }
+                self.running_state(remaining)
+            }
+            SnapshotState::Paused { remaining_ms } => TimerState::Paused {
+                remaining_duration: Duration::from_millis(remaining_ms),
+            },
+            SnapshotState::Finished => {
+                self.completion_time = Some(now);
+                TimerState::Finished
+            }
+            SnapshotState::StopwatchRunning { started_ms } => TimerState::StopwatchRunning {
+                started_at: now,
+                elapsed_before: since(started_ms),
+            },
+            SnapshotState::StopwatchPaused { elapsed_ms } => TimerState::StopwatchPaused {
+                elapsed: Duration::from_millis(elapsed_ms),
+            },
+            SnapshotState::Overtime { finished_ms } => {
+                let finished_at = now.checked_sub(since(finished_ms)).unwrap_or(now);
+                self.completion_time = Some(finished_at);
+                TimerState::Overtime { finished_at }
+            }
+        };
+    }
+    
+    /// Most recent laps, newest first
+    pub fn recent_laps(&self, count: usize) -> impl Iterator<Item = &Lap> {
+        self.laps.iter().rev().take(count)
+    }
 }
 
 impl Default for Timer {
@@ -130,13 +480,14 @@ impl TimerControl for Timer {
         
         // Check current state
         match self.state {
-            TimerState::Stopped | TimerState::Finished => {
-                self.state = TimerState::Running {
-                    started_at: Instant::now(),
-                    remaining_duration: duration,
-                };
+            TimerState::Stopped | TimerState::Finished | TimerState::Overtime { .. } => {
+                self.state = self.running_state(duration);
                 self.original_duration = duration;
                 self.completion_time = None;
+                self.suspended_for = Duration::ZERO;
+                self.finished_while_suspended = false;
+                self.laps.clear();
+                self.alarm_target = None;
                 Ok(())
             }
             _ => Err(TimerError::InvalidState(
@@ -147,13 +498,19 @@ impl TimerControl for Timer {
     
     fn pause(&mut self) -> Result<(), TimerError> {
         match &self.state {
-            TimerState::Running { started_at, remaining_duration } => {
-                let current_remaining = Self::calculate_remaining_time(*started_at, *remaining_duration);
+            TimerState::Running { started_at, remaining_duration, .. } => {
+                let current_remaining = self.calculate_remaining_time(*started_at, *remaining_duration);
                 self.state = TimerState::Paused {
                     remaining_duration: current_remaining,
                 };
                 Ok(())
             }
+            TimerState::StopwatchRunning { started_at, elapsed_before } => {
+                self.state = TimerState::StopwatchPaused {
+                    elapsed: *elapsed_before + self.clock.elapsed_since(*started_at),
+                };
+                Ok(())
+            }
             _ => Err(TimerError::InvalidState(
                 "Cannot pause timer: timer is not running".to_string()
             ))
@@ -163,9 +520,20 @@ impl TimerControl for Timer {
     fn resume(&mut self) -> Result<(), TimerError> {
         match &self.state {
             TimerState::Paused { remaining_duration } => {
-                self.state = TimerState::Running {
-                    started_at: Instant::now(),
-                    remaining_duration: *remaining_duration,
+                match self.alarm_target {
+                    // An alarm keeps its target; resuming catches up with the wall clock
+                    Some(target) => {
+                        self.state = self.running_state(*remaining_duration);
+                        self.tick_alarm(target);
+                    }
+                    None => self.state = self.running_state(*remaining_duration),
+                }
+                Ok(())
+            }
+            TimerState::StopwatchPaused { elapsed } => {
+                self.state = TimerState::StopwatchRunning {
+                    started_at: self.clock.now(),
+                    elapsed_before: *elapsed,
                 };
                 Ok(())
             }
@@ -179,6 +547,53 @@ impl TimerControl for Timer {
         self.state = TimerState::Stopped;
         self.original_duration = Duration::from_secs(0);
         self.completion_time = None;
+        self.suspended_for = Duration::ZERO;
+        self.finished_while_suspended = false;
+        self.laps.clear();
+        self.alarm_target = None;
+    }
+    
+    fn add_time(&mut self, amount: Duration) -> Result<(), TimerError> {
+        if self.is_alarm() {
+            return Err(TimerError::InvalidState(
+                "Cannot adjust an alarm: set a new alarm time instead".to_string()
+            ));
+        }
+        
+        let remaining = match self.state {
+            TimerState::Running { .. } | TimerState::Paused { .. } => self.remaining_time().unwrap_or_default(),
+            _ => return Err(TimerError::InvalidState(
+                "Cannot add time: countdown is not running or paused".to_string()
+            ))
+        };
+        
+        // The extended countdown is held to the same limits as a new one
+        Self::validate_duration(remaining + amount)?;
+        
+        self.original_duration += amount;
+        self.set_remaining(remaining + amount);
+        Ok(())
+    }
+    
+    fn subtract_time(&mut self, amount: Duration) -> Result<(), TimerError> {
+        if self.is_alarm() {
+            return Err(TimerError::InvalidState(
+                "Cannot adjust an alarm: set a new alarm time instead".to_string()
+            ));
+        }
+        
+        let remaining = match self.state {
+            TimerState::Running { .. } | TimerState::Paused { .. } => self.remaining_time().unwrap_or_default(),
+            _ => return Err(TimerError::InvalidState(
+                "Cannot subtract time: countdown is not running or paused".to_string()
+            ))
+        };
+        
+        // Clamp at zero, which finishes the countdown
+        let removed = amount.min(remaining);
+        self.original_duration = self.original_duration.saturating_sub(removed);
+        self.set_remaining(remaining - removed);
+        Ok(())
     }
     
     fn state(&self) -> &TimerState {
@@ -187,8 +602,8 @@ impl TimerControl for Timer {
     
     fn remaining_time(&self) -> Option<Duration> {
         match &self.state {
-            TimerState::Running { started_at, remaining_duration } => {
-                Some(Self::calculate_remaining_time(*started_at, *remaining_duration))
+            TimerState::Running { started_at, remaining_duration, .. } => {
+                Some(self.calculate_remaining_time(*started_at, *remaining_duration))
             }
             TimerState::Paused { remaining_duration } => Some(*remaining_duration),
             _ => None,
@@ -196,24 +611,47 @@ impl TimerControl for Timer {
     }
     
     fn is_finished(&self) -> bool {
-        matches!(self.state, TimerState::Finished)
+        self.state.is_finished()
     }
     
     fn tick(&mut self) -> bool {
+        if let Some(target) = self.alarm_target {
+            return self.tick_alarm(target);
+        }
+        
         match &self.state {
-            TimerState::Running { started_at, remaining_duration } => {
-                let current_remaining = Self::calculate_remaining_time(*started_at, *remaining_duration);
+            TimerState::Running { started_at, remaining_duration, deadline } => {
+                let current_remaining = self.calculate_remaining_time(*started_at, *remaining_duration);
+                let wall_remaining = self.calculate_wall_remaining_time(*deadline);
+                
+                // The monotonic clock may stop while the system sleeps; the
+                // wall-clock deadline does not, so a large gap means we missed time
+                if current_remaining > wall_remaining + SUSPEND_DETECTION_THRESHOLD {
+                    self.suspended_for += current_remaining - wall_remaining;
+                    
+                    if wall_remaining.is_zero() {
+                        // Back-date the finish to when the wall-clock deadline passed
+                        let overdue = self.clock.wall_now()
+                            .duration_since(*deadline)
+                            .unwrap_or(Duration::ZERO);
+                        let now = self.clock.now();
+                        self.finished_while_suspended = true;
+                        self.finish(now.checked_sub(overdue).unwrap_or(now));
+                    } else {
+                        self.state = self.running_state(wall_remaining);
+                    }
+                    return true; // State changed
+                }
                 
                 if current_remaining.is_zero() {
-                    // Timer has finished
-                    self.state = TimerState::Finished;
-                    self.completion_time = Some(Instant::now());
+                    // Timer has finished exactly when its remaining time ran out
+                    self.finish(*started_at + *remaining_duration);
                     true // State changed
                 } else {
                     false // No state change
                 }
             }
-            _ => false // No state change for non-running timers
+            _ => false // No state change for non-running timers or stopwatches
         }
     }
 }
@@ -221,7 +659,7 @@ impl TimerControl for Timer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::thread;
+    use crate::models::clock::{Clock, ManualClock};
     
     #[test]
     fn test_new_timer_is_stopped() {
@@ -305,32 +743,403 @@ mod tests {
     
     #[test]
     fn test_timer_completion() {
-        let mut timer = Timer::new();
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
         
-        // Start very short timer
         timer.start(Duration::from_millis(10)).unwrap();
         
-        // Wait for completion
-        thread::sleep(Duration::from_millis(20));
+        // Not finished until the full duration has elapsed
+        clock.advance(Duration::from_millis(9));
+        assert!(!timer.tick());
+        assert_eq!(timer.remaining_time(), Some(Duration::from_millis(1)));
         
         // Tick should detect completion
+        clock.advance(Duration::from_millis(1));
         let state_changed = timer.tick();
         assert!(state_changed);
         assert!(timer.is_finished());
         assert!(matches!(timer.state, TimerState::Finished));
+        assert_eq!(timer.completion_time, Some(clock.now()));
     }
     
     #[test]
     fn test_remaining_time_decreases() {
-        let mut timer = Timer::new();
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
         let duration = Duration::from_secs(10);
         
         timer.start(duration).unwrap();
-        let initial_remaining = timer.remaining_time().unwrap();
+        assert_eq!(timer.remaining_time(), Some(duration));
         
-        thread::sleep(Duration::from_millis(100));
-        let updated_remaining = timer.remaining_time().unwrap();
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(timer.remaining_time(), Some(Duration::from_millis(9900)));
+    }
+    
+    #[test]
+    fn test_full_session_with_pause() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(25 * 60)).unwrap();
+        clock.advance(Duration::from_secs(10 * 60));
+        timer.pause().unwrap();
+        
+        // Time spent paused does not count against the timer
+        clock.advance(Duration::from_secs(60 * 60));
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(15 * 60)));
+        
+        timer.resume().unwrap();
+        for _ in 0..(15 * 60 - 1) {
+            clock.advance(Duration::from_secs(1));
+            assert!(!timer.tick());
+        }
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(1)));
+        
+        clock.advance(Duration::from_secs(1));
+        assert!(timer.tick());
+        assert!(timer.is_finished());
+    }
+    
+    #[test]
+    fn test_stopwatch_counts_up_and_pauses() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start_stopwatch().unwrap();
+        assert!(timer.is_stopwatch());
+        assert_eq!(timer.remaining_time(), None);
+        
+        clock.advance(Duration::from_secs(90));
+        assert!(!timer.tick());
+        assert_eq!(timer.elapsed_time(), Some(Duration::from_secs(90)));
+        
+        timer.pause().unwrap();
+        assert!(matches!(timer.state, TimerState::StopwatchPaused { .. }));
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(timer.elapsed_time(), Some(Duration::from_secs(90)));
+        
+        timer.resume().unwrap();
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(timer.elapsed_time(), Some(Duration::from_secs(100)));
+        assert!(!timer.is_finished());
+        
+        // Cannot start a second session without reset
+        assert!(timer.start_stopwatch().is_err());
+        assert!(timer.start(Duration::from_secs(60)).is_err());
+        
+        timer.reset();
+        assert!(matches!(timer.state, TimerState::Stopped));
+        assert_eq!(timer.elapsed_time(), None);
+    }
+    
+    #[test]
+    fn test_record_laps() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        // No laps before the timer runs
+        assert!(timer.record_lap().is_err());
+        
+        timer.start_stopwatch().unwrap();
+        clock.advance(Duration::from_secs(30));
+        let first = timer.record_lap().unwrap();
+        assert_eq!(first, Lap { index: 1, split: Duration::from_secs(30), lap: Duration::from_secs(30) });
+        
+        clock.advance(Duration::from_secs(45));
+        let second = timer.record_lap().unwrap();
+        assert_eq!(second, Lap { index: 2, split: Duration::from_secs(75), lap: Duration::from_secs(45) });
+        
+        // Laps cannot be recorded while paused
+        timer.pause().unwrap();
+        assert!(timer.record_lap().is_err());
+        
+        let recent: Vec<usize> = timer.recent_laps(5).map(|lap| lap.index).collect();
+        assert_eq!(recent, vec![2, 1]);
+        
+        timer.reset();
+        assert!(timer.laps.is_empty());
+    }
+    
+    #[test]
+    fn test_lap_history_is_bounded() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(60 * 60)).unwrap();
+        for _ in 0..(MAX_LAP_HISTORY + 5) {
+            clock.advance(Duration::from_secs(1));
+            timer.record_lap().unwrap();
+        }
+        
+        assert_eq!(timer.laps.len(), MAX_LAP_HISTORY);
+        assert_eq!(timer.laps.front().unwrap().index, 6);
+        let last = timer.laps.back().unwrap();
+        assert_eq!(last.index, MAX_LAP_HISTORY + 5);
+        assert_eq!(last.lap, Duration::from_secs(1));
+    }
+    
+    #[test]
+    fn test_countdown_elapsed_time() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(15));
+        assert_eq!(timer.elapsed_time(), Some(Duration::from_secs(15)));
+    }
+    
+    #[test]
+    fn test_add_and_subtract_time() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        // Not allowed before the countdown starts
+        assert!(timer.add_time(Duration::from_secs(60)).is_err());
+        
+        timer.start(Duration::from_secs(10 * 60)).unwrap();
+        clock.advance(Duration::from_secs(2 * 60));
+        
+        timer.add_time(Duration::from_secs(60)).unwrap();
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(9 * 60)));
+        assert_eq!(timer.elapsed_time(), Some(Duration::from_secs(2 * 60)));
+        
+        timer.pause().unwrap();
+        timer.subtract_time(Duration::from_secs(3 * 60)).unwrap();
+        assert!(matches!(timer.state, TimerState::Paused { .. }));
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(6 * 60)));
+        
+        // Adjustments persist after resuming
+        timer.resume().unwrap();
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(5 * 60)));
+    }
+    
+    #[test]
+    fn test_add_time_respects_24_hour_cap() {
+        let mut timer = Timer::with_clock(ManualClock::shared());
+        timer.start(Duration::from_secs(23 * 60 * 60)).unwrap();
+        timer.pause().unwrap();
+        
+        let result = timer.add_time(Duration::from_secs(2 * 60 * 60));
+        assert!(matches!(result, Err(TimerError::InvalidDuration(_))));
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(23 * 60 * 60)));
+    }
+    
+    #[test]
+    fn test_subtract_time_clamps_and_finishes() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(60)).unwrap();
+        timer.subtract_time(Duration::from_secs(5 * 60)).unwrap();
+        
+        assert!(timer.is_finished());
+        assert_eq!(timer.completion_time, Some(clock.now()));
+        
+        // Stopwatches have no remaining time to adjust
+        timer.start_stopwatch().unwrap();
+        assert!(timer.subtract_time(Duration::from_secs(1)).is_err());
+    }
+    
+    #[test]
+    fn test_overtime_counts_past_zero() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        timer.overtime_enabled = true;
+        
+        timer.start(Duration::from_secs(60)).unwrap();
+        clock.advance(Duration::from_secs(60 + 5));
+        
+        // Overtime is measured from the moment the countdown hit zero
+        assert!(timer.tick());
+        assert!(timer.is_finished());
+        assert!(matches!(timer.state, TimerState::Overtime { .. }));
+        assert_eq!(timer.overtime(), Some(Duration::from_secs(5)));
+        
+        clock.advance(Duration::from_secs(128));
+        assert!(!timer.tick());
+        assert_eq!(timer.overtime(), Some(Duration::from_secs(133)));
+        
+        // Overtime cannot be paused, only dismissed or restarted
+        assert!(timer.pause().is_err());
+        timer.reset();
+        assert_eq!(timer.overtime(), None);
+    }
+    
+    #[test]
+    fn test_overtime_after_suspend_includes_time_asleep() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        timer.overtime_enabled = true;
+        
+        timer.start(Duration::from_secs(10 * 60)).unwrap();
+        clock.suspend(Duration::from_secs(15 * 60));
+        
+        assert!(timer.tick());
+        assert!(timer.finished_while_suspended);
+        assert_eq!(timer.overtime(), Some(Duration::from_secs(5 * 60)));
+    }
+    
+    #[test]
+    fn test_suspend_past_deadline_finishes_immediately() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(20 * 60)).unwrap();
+        clock.advance(Duration::from_secs(60));
+        clock.suspend(Duration::from_secs(60 * 60));
+        
+        assert!(timer.tick());
+        assert!(timer.is_finished());
+        assert!(timer.finished_while_suspended);
+        assert_eq!(timer.suspended_for, Duration::from_secs(19 * 60));
+    }
+    
+    #[test]
+    fn test_short_suspend_reports_missed_interval() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(20 * 60)).unwrap();
+        clock.suspend(Duration::from_secs(5 * 60));
+        
+        assert!(timer.tick());
+        assert!(matches!(timer.state, TimerState::Running { .. }));
+        assert!(!timer.finished_while_suspended);
+        assert_eq!(timer.suspended_for, Duration::from_secs(5 * 60));
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(15 * 60)));
+        
+        // Subsequent ticks do not report the same interval again
+        clock.advance(Duration::from_secs(1));
+        assert!(!timer.tick());
+        assert_eq!(timer.suspended_for, Duration::from_secs(5 * 60));
+    }
+    
+    #[test]
+    fn test_alarm_finishes_at_wall_clock_target() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        let target = clock.wall_now() + Duration::from_secs(30 * 60);
+        timer.start_alarm(target).unwrap();
+        assert!(timer.is_alarm());
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(30 * 60)));
+        
+        // The system clock jumps forward (e.g. DST or a manual correction)
+        clock.advance(Duration::from_secs(60));
+        clock.suspend(Duration::from_secs(10 * 60));
+        assert!(timer.tick());
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(19 * 60)));
+        
+        // ...and back again: the alarm still ends at its target
+        clock.set_wall_back(Duration::from_secs(20 * 60));
+        assert!(timer.tick());
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(39 * 60)));
+        assert_eq!(timer.suspended_for, Duration::ZERO);
+        
+        clock.advance(Duration::from_secs(39 * 60));
+        assert!(timer.tick());
+        assert!(timer.is_finished());
+    }
+    
+    #[test]
+    fn test_alarm_validation_and_adjustment() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        assert!(timer.start_alarm(clock.wall_now()).is_err());
+        assert!(timer.start_alarm(clock.wall_now() + MAX_ALARM_LEAD * 2).is_err());
+        
+        // Alarms may be further ahead than the 24 hour countdown cap
+        timer.start_alarm(clock.wall_now() + Duration::from_secs(3 * 24 * 60 * 60)).unwrap();
+        assert!(timer.add_time(Duration::from_secs(60)).is_err());
+        
+        // Pausing does not move the target; resuming catches up
+        timer.pause().unwrap();
+        clock.advance(Duration::from_secs(60 * 60));
+        timer.resume().unwrap();
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(71 * 60 * 60)));
+        
+        timer.reset();
+        assert!(!timer.is_alarm());
+    }
+    
+    #[test]
+    fn test_snapshot_restores_running_countdown() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        timer.start(Duration::from_secs(10 * 60)).unwrap();
+        clock.advance(Duration::from_secs(60));
+        let snapshot = timer.snapshot();
+        
+        // The app is down for three minutes; a fresh process has a fresh monotonic clock
+        clock.suspend(Duration::from_secs(3 * 60));
+        let mut restored = Timer::with_clock(clock.clone());
+        restored.restore(&snapshot);
+        
+        assert!(matches!(restored.state, TimerState::Running { .. }));
+        assert_eq!(restored.remaining_time(), Some(Duration::from_secs(6 * 60)));
+        assert_eq!(restored.original_duration, Duration::from_secs(10 * 60));
+    }
+    
+    #[test]
+    fn test_snapshot_past_deadline_finishes_on_restore() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        timer.overtime_enabled = true;
+        timer.start(Duration::from_secs(60)).unwrap();
+        let snapshot = timer.snapshot();
+        
+        clock.suspend(Duration::from_secs(5 * 60));
+        timer.restore(&snapshot);
+        assert!(timer.is_finished());
+        assert!(timer.finished_while_suspended);
+        assert_eq!(timer.overtime(), Some(Duration::from_secs(4 * 60)));
+    }
+    
+    #[test]
+    fn test_snapshot_round_trips_other_states() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(90)).unwrap();
+        clock.advance(Duration::from_secs(30));
+        timer.pause().unwrap();
+        let paused = timer.snapshot();
+        clock.suspend(Duration::from_secs(60 * 60));
+        timer.restore(&paused);
+        assert_eq!(timer.state, TimerState::Paused { remaining_duration: Duration::from_secs(60) });
+        
+        timer.reset();
+        timer.start_stopwatch().unwrap();
+        clock.advance(Duration::from_secs(45));
+        let stopwatch = timer.snapshot();
+        clock.suspend(Duration::from_secs(15));
+        timer.restore(&stopwatch);
+        assert_eq!(timer.elapsed_time(), Some(Duration::from_secs(60)));
+        
+        timer.reset();
+        let target = clock.wall_now() + Duration::from_secs(2 * 60 * 60);
+        timer.start_alarm(target).unwrap();
+        let alarm = timer.snapshot();
+        timer.reset();
+        timer.restore(&alarm);
+        assert!(timer.is_alarm());
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(2 * 60 * 60)));
+    }
+    
+    #[test]
+    fn test_suspend_while_paused_is_ignored() {
+        let clock = ManualClock::shared();
+        let mut timer = Timer::with_clock(clock.clone());
+        
+        timer.start(Duration::from_secs(60)).unwrap();
+        timer.pause().unwrap();
+        clock.suspend(Duration::from_secs(60 * 60));
+        timer.resume().unwrap();
         
-        assert!(updated_remaining < initial_remaining);
+        assert!(!timer.tick());
+        assert_eq!(timer.remaining_time(), Some(Duration::from_secs(60)));
+        assert_eq!(timer.suspended_for, Duration::ZERO);
     }
 }
\ No newline at end of file
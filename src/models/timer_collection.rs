@@ -0,0 +1,149 @@
+// Collection of independent named timers shown alongside the main timer
+use std::time::Duration;
+
+use crate::models::clock::SharedClock;
+use crate::models::timer::{Timer, TimerControl, TimerError, TimerState};
+
+/// Stable identifier of a named timer; never reused within a collection
+pub type TimerId = u32;
+
+/// A countdown with a user-facing name and the duration it restarts with
+#[derive(Debug, Clone)]
+pub struct NamedTimer {
+    pub id: TimerId,
+    pub name: String,
+    pub preset: Duration,
+    pub timer: Timer,
+}
+
+/// Ordered set of named timers
+#[derive(Debug, Clone)]
+pub struct TimerCollection {
+    timers: Vec<NamedTimer>,
+    next_id: TimerId,
+    clock: SharedClock,
+}
+
+impl NamedTimer {
+    /// Start the countdown from its preset, or resume/pause it if already in progress
+    pub fn toggle(&mut self) -> Result<(), TimerError> {
+        match self.timer.state() {
+            TimerState::Stopped | TimerState::Finished | TimerState::Overtime { .. } => {
+                self.timer.reset();
+                self.timer.start(self.preset)
+            }
+            state if state.is_active() => self.timer.pause(),
+            _ => self.timer.resume(),
+        }
+    }
+}
+
+impl TimerCollection {
+    /// Create an empty collection whose timers use the given clock
+    pub fn new(clock: SharedClock) -> Self {
+        Self {
+            timers: Vec::new(),
+            next_id: 1,
+            clock,
+        }
+    }
+
+    /// Add a stopped timer and return its ID
+    pub fn add(&mut self, name: &str, preset: Duration) -> TimerId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.timers.push(NamedTimer {
+            id,
+            name: name.to_string(),
+            preset,
+            timer: Timer::with_clock(self.clock.clone()),
+        });
+        id
+    }
+
+    /// Remove a timer, returning it if it existed
+    pub fn remove(&mut self, id: TimerId) -> Option<NamedTimer> {
+        let index = self.timers.iter().position(|t| t.id == id)?;
+        Some(self.timers.remove(index))
+    }
+
+    pub fn get(&self, id: TimerId) -> Option<&NamedTimer> {
+        self.timers.iter().find(|t| t.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: TimerId) -> Option<&mut NamedTimer> {
+        self.timers.iter_mut().find(|t| t.id == id)
+    }
+
+    /// Timers in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = &NamedTimer> {
+        self.timers.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Tick every timer, returning the IDs of those that just finished
+    pub fn tick(&mut self) -> Vec<TimerId> {
+        self.timers
+            .iter_mut()
+            .filter_map(|named| {
+                let changed = named.timer.tick();
+                (changed && named.timer.is_finished()).then_some(named.id)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::clock::ManualClock;
+
+    #[test]
+    fn test_ids_are_stable_and_not_reused() {
+        let mut timers = TimerCollection::new(ManualClock::shared());
+        let pasta = timers.add("pasta", Duration::from_secs(9 * 60));
+        let sauce = timers.add("sauce", Duration::from_secs(20 * 60));
+
+        assert!(timers.remove(pasta).is_some());
+        let bread = timers.add("bread", Duration::from_secs(30 * 60));
+
+        assert_ne!(bread, pasta);
+        assert_eq!(timers.get(sauce).unwrap().name, "sauce");
+        let names: Vec<&str> = timers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["sauce", "bread"]);
+    }
+
+    #[test]
+    fn test_timers_run_independently() {
+        let clock = ManualClock::shared();
+        let mut timers = TimerCollection::new(clock.clone());
+        let pasta = timers.add("pasta", Duration::from_secs(9 * 60));
+        let sauce = timers.add("sauce", Duration::from_secs(20 * 60));
+
+        timers.get_mut(pasta).unwrap().toggle().unwrap();
+        timers.get_mut(sauce).unwrap().toggle().unwrap();
+        clock.advance(Duration::from_secs(60));
+        timers.get_mut(sauce).unwrap().toggle().unwrap(); // pause sauce
+
+        clock.advance(Duration::from_secs(8 * 60));
+        assert_eq!(timers.tick(), vec![pasta]);
+        assert_eq!(
+            timers.get(sauce).unwrap().timer.remaining_time(),
+            Some(Duration::from_secs(19 * 60))
+        );
+
+        // Toggling a finished timer restarts it from its preset
+        timers.get_mut(pasta).unwrap().toggle().unwrap();
+        assert_eq!(
+            timers.get(pasta).unwrap().timer.remaining_time(),
+            Some(Duration::from_secs(9 * 60))
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+// Serializable timer snapshots for restoring timers after a restart or crash
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::models::pomodoro::PomodoroSession;
+use crate::models::sequence::SequenceRun;
+
+/// Wall-clock form of `TimerState`
+///
+/// `Instant` is meaningless across processes, so every running state is
+/// anchored to wall-clock milliseconds since the Unix epoch instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum SnapshotState {
+    Stopped,
+    Running { deadline_ms: u64 },
+    Paused { remaining_ms: u64 },
+    Finished,
+    StopwatchRunning { started_ms: u64 }, // Wall time the stopwatch would have started, excluding pauses
+    StopwatchPaused { elapsed_ms: u64 },
+    Overtime { finished_ms: u64 },
+}
+
+/// Snapshot of a single timer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimerSnapshot {
+    pub state: SnapshotState,
+    pub original_duration_ms: u64,
+    pub alarm_target_ms: Option<u64>,
+}
+
+/// Snapshot of a named timer; IDs are reassigned on restore
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedTimerSnapshot {
+    pub name: String,
+    pub preset_ms: u64,
+    pub timer: TimerSnapshot,
+}
+
+/// Everything needed to bring the timers back after a restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub saved_at_ms: u64,
+    pub timer: TimerSnapshot,
+    pub label: Option<String>, // Phase or step label shown when the snapshot was taken
+    pub pomodoro: Option<PomodoroSession>,
+    pub sequence: Option<SequenceRun>,
+    pub named_timers: Vec<NamedTimerSnapshot>,
+}
+
+/// Milliseconds since the Unix epoch (zero for times before it)
+pub fn to_epoch_ms(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Inverse of `to_epoch_ms`
+pub fn from_epoch_ms(ms: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(ms)
+}
+
+/// Duration as whole milliseconds
+pub fn to_ms(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
\ No newline at end of file
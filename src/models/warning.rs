@@ -0,0 +1,123 @@
+// Pre-finish warnings given as a countdown approaches zero
+use std::time::Duration;
+
+use crate::models::config::{WarningAction, WarningThreshold};
+use crate::models::duration_parser::parse_duration;
+
+/// Parsed warning threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub remaining: Duration,
+    pub actions: Vec<WarningAction>,
+}
+
+impl Warning {
+    pub fn has_action(&self, action: WarningAction) -> bool {
+        self.actions.contains(&action)
+    }
+}
+
+/// Reports each threshold at most once per countdown
+///
+/// A countdown is armed when it starts; thresholds it starts within never fire,
+/// and pausing or adding time back above a threshold does not re-arm it.
+#[derive(Debug, Clone, Default)]
+pub struct WarningTracker {
+    warnings: Vec<Warning>, // Most remaining time first
+    fired: Vec<bool>,
+}
+
+impl WarningTracker {
+    /// Build from configuration, skipping thresholds that fail validation
+    pub fn from_config(thresholds: &[WarningThreshold]) -> Self {
+        let mut warnings: Vec<Warning> = thresholds
+            .iter()
+            .filter_map(|threshold| {
+                let remaining = parse_duration(&threshold.remaining).ok()?;
+                (!remaining.is_zero()).then(|| Warning {
+                    remaining,
+                    actions: threshold.actions.clone(),
+                })
+            })
+            .collect();
+        warnings.sort_by_key(|warning| std::cmp::Reverse(warning.remaining));
+        let fired = vec![true; warnings.len()]; // Disarmed until a countdown starts
+
+        Self { warnings, fired }
+    }
+
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Start tracking a countdown with the given time left
+    pub fn arm(&mut self, remaining: Duration) {
+        for (fired, warning) in self.fired.iter_mut().zip(&self.warnings) {
+            *fired = remaining <= warning.remaining;
+        }
+    }
+
+    /// Stop tracking; nothing fires until the tracker is armed again
+    pub fn disarm(&mut self) {
+        self.fired.iter_mut().for_each(|fired| *fired = true);
+    }
+
+    /// Record thresholds crossed by now, returning the most urgent one that had not fired
+    ///
+    /// Jumping past several thresholds at once (e.g. subtracting time) fires only the last.
+    pub fn check(&mut self, remaining: Duration) -> Option<&Warning> {
+        let mut crossed = None;
+        for (index, warning) in self.warnings.iter().enumerate() {
+            if !self.fired[index] && remaining <= warning.remaining {
+                self.fired[index] = true;
+                crossed = Some(index);
+            }
+        }
+        crossed.map(|index| &self.warnings[index])
+    }
+
+    /// Most urgent threshold the remaining time is within, used for visual state
+    pub fn active(&self, remaining: Duration) -> Option<&Warning> {
+        self.warnings.iter().rev().find(|warning| remaining <= warning.remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker() -> WarningTracker {
+        WarningTracker::from_config(&[
+            WarningThreshold::new("0:10", vec![WarningAction::Pulse]),
+            WarningThreshold::new("5m", vec![WarningAction::Color]),
+            WarningThreshold::new("1m", vec![WarningAction::Sound]),
+        ])
+    }
+
+    #[test]
+    fn test_each_threshold_fires_once() {
+        let mut tracker = tracker();
+        assert!(tracker.check(Duration::from_secs(5)).is_none(), "disarmed until a countdown starts");
+
+        tracker.arm(Duration::from_secs(10 * 60));
+        assert!(tracker.check(Duration::from_secs(301)).is_none());
+        assert_eq!(tracker.check(Duration::from_secs(300)).unwrap().remaining, Duration::from_secs(300));
+        assert!(tracker.check(Duration::from_secs(299)).is_none());
+
+        // Skipping past two thresholds reports only the most urgent one
+        assert_eq!(tracker.check(Duration::from_secs(9)).unwrap().remaining, Duration::from_secs(10));
+        assert!(tracker.check(Duration::from_secs(1)).is_none());
+    }
+
+    #[test]
+    fn test_arming_skips_thresholds_already_inside() {
+        let mut tracker = tracker();
+        tracker.arm(Duration::from_secs(60));
+        assert!(tracker.check(Duration::from_secs(59)).is_none());
+        assert!(tracker.check(Duration::from_secs(10)).unwrap().has_action(WarningAction::Pulse));
+
+        let active = tracker.active(Duration::from_secs(45)).unwrap();
+        assert_eq!(active.remaining, Duration::from_secs(60));
+        assert!(tracker.active(Duration::from_secs(400)).is_none());
+    }
+}
@@ -0,0 +1,205 @@
+// Configuration file formats, chosen by file extension
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use toml_edit::{DocumentMut, Item, Table};
+
+use crate::models::config::ConfigError;
+
+/// Supported configuration file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// All formats, in the order a config file is looked for
+    pub const ALL: [ConfigFormat; 3] = [ConfigFormat::Json, ConfigFormat::Toml, ConfigFormat::Yaml];
+
+    /// Format named by the path's extension (`json`, `toml`, `yaml` or `yml`)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+    }
+
+    /// Extension used for files written in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ConfigFormat::Json => "json",
+            ConfigFormat::Toml => "toml",
+            ConfigFormat::Yaml => "yaml",
+        }
+    }
+
+    /// Read a file's contents into a format-neutral value
+    pub fn parse(&self, text: &str) -> Result<Value, ConfigError> {
+        match self {
+            ConfigFormat::Json => serde_json::from_str(text).map_err(|e| ConfigError::InvalidFormat(e.to_string())),
+            ConfigFormat::Toml => toml::from_str(text).map_err(|e| ConfigError::InvalidFormat(e.to_string())),
+            ConfigFormat::Yaml => serde_yaml::from_str(text).map_err(|e| ConfigError::InvalidFormat(e.to_string())),
+        }
+    }
+
+    /// Write a value in this format
+    ///
+    /// For TOML, the previous contents of the file can be passed in; keys that
+    /// still exist keep their comments and layout, and only their values change.
+    pub fn render(&self, value: &Value, previous: Option<&str>) -> Result<String, ConfigError> {
+        match self {
+            ConfigFormat::Json => {
+                serde_json::to_string_pretty(value).map_err(|e| ConfigError::WriteError(e.to_string()))
+            }
+            ConfigFormat::Yaml => serde_yaml::to_string(value).map_err(|e| ConfigError::WriteError(e.to_string())),
+            ConfigFormat::Toml => {
+                // TOML has no null; a cleared setting is written as "" and read back as `None`
+                let text = toml::to_string(&nulls_as_empty(value))
+                    .map_err(|e| ConfigError::WriteError(e.to_string()))?;
+                let Some(mut document) = previous.and_then(|text| text.parse::<DocumentMut>().ok()) else {
+                    return Ok(text);
+                };
+                let fresh: DocumentMut = text.parse().map_err(|e: toml_edit::TomlError| ConfigError::WriteError(e.to_string()))?;
+                merge_table(document.as_table_mut(), fresh.as_table());
+                Ok(document.to_string())
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for ConfigFormat {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(ConfigFormat::Json),
+            "toml" => Ok(ConfigFormat::Toml),
+            "yaml" | "yml" => Ok(ConfigFormat::Yaml),
+            other => Err(ConfigError::InvalidFormat(format!("unsupported format `{}`", other))),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// Serialize into a value whose floats read back the way they were written
+///
+/// `f32` settings go through their shortest text form, so `0.3` is stored as
+/// `0.3` rather than `0.30000001192092896`.
+pub fn to_plain_value<T: Serialize>(data: &T) -> Result<Value, ConfigError> {
+    let text = serde_json::to_string(data).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+    serde_json::from_str(&text).map_err(|e| ConfigError::WriteError(e.to_string()))
+}
+
+fn nulls_as_empty(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::from(""),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, field)| (key.clone(), nulls_as_empty(field)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(nulls_as_empty).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Bring `existing` up to date with `fresh` while keeping its comments and key order
+fn merge_table(existing: &mut Table, fresh: &Table) {
+    let stale: Vec<String> = existing
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| !fresh.contains_key(key))
+        .collect();
+    for key in stale {
+        existing.remove(&key);
+    }
+
+    for (key, item) in fresh.iter() {
+        match (existing.get_mut(key), item) {
+            (Some(Item::Table(old)), Item::Table(new)) => merge_table(old, new),
+            (Some(Item::ArrayOfTables(old)), Item::ArrayOfTables(new)) => {
+                while old.len() > new.len() {
+                    old.remove(old.len() - 1);
+                }
+                for (index, table) in new.iter().enumerate() {
+                    match old.get_mut(index) {
+                        Some(old_table) => merge_table(old_table, table),
+                        None => old.push(table.clone()),
+                    }
+                }
+            }
+            (Some(Item::Value(old)), Item::Value(new)) => {
+                // The value's decor holds its trailing comment
+                let decor = old.decor().clone();
+                *old = new.clone();
+                *old.decor_mut() = decor;
+            }
+            _ => {
+                existing.insert(key, item.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::config::Configuration;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(ConfigFormat::from_path(&PathBuf::from("config.json")), Some(ConfigFormat::Json));
+        assert_eq!(ConfigFormat::from_path(&PathBuf::from("config.TOML")), Some(ConfigFormat::Toml));
+        assert_eq!(ConfigFormat::from_path(&PathBuf::from("config.yml")), Some(ConfigFormat::Yaml));
+        assert_eq!(ConfigFormat::from_path(&PathBuf::from("config.ini")), None);
+        assert_eq!(ConfigFormat::from_path(&PathBuf::from("config")), None);
+    }
+
+    #[test]
+    fn test_every_format_round_trips() {
+        let mut config = Configuration::default();
+        config.display.transparency = 0.3;
+        config.hotkeys.reset = None;
+        let value = to_plain_value(&config).unwrap();
+
+        for format in ConfigFormat::ALL {
+            let text = format.render(&value, None).unwrap();
+            let parsed: Configuration = serde_json::from_value(format.parse(&text).unwrap()).unwrap();
+            assert_eq!(parsed.display.transparency, 0.3, "{}", format);
+            assert_eq!(parsed.hotkeys.reset, None, "{}", format);
+            assert_eq!(parsed.hotkeys.start_stop, config.hotkeys.start_stop, "{}", format);
+            assert_eq!(parsed.notifications.warnings.len(), config.notifications.warnings.len(), "{}", format);
+            assert!(parsed.is_valid(), "{}", format);
+        }
+        assert!(ConfigFormat::Toml.render(&value, None).unwrap().contains("transparency = 0.3\n"));
+    }
+
+    #[test]
+    fn test_toml_comments_survive_a_save() {
+        let mut config = Configuration::default();
+        let original = ConfigFormat::Toml.render(&to_plain_value(&config).unwrap(), None).unwrap();
+        let edited = original
+            .replacen("[hotkeys]", "# Global shortcuts\n[hotkeys]", 1)
+            .replacen("transparency = 0.3", "transparency = 0.3 # barely visible", 1);
+
+        config.display.transparency = 0.5;
+        config.hotkeys.reset = None;
+        let saved = ConfigFormat::Toml.render(&to_plain_value(&config).unwrap(), Some(&edited)).unwrap();
+        assert!(saved.contains("# Global shortcuts\n[hotkeys]"));
+        assert!(saved.contains("transparency = 0.5 # barely visible"));
+        assert!(saved.contains("reset = \"\""));
+
+        let parsed: Configuration = serde_json::from_value(ConfigFormat::Toml.parse(&saved).unwrap()).unwrap();
+        assert_eq!(parsed.display.transparency, 0.5);
+        assert_eq!(parsed.hotkeys.reset, None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,670 @@
+// Layered configuration: defaults, system file, user file, environment and command line
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::models::config::{ConfigError, Configuration, CONFIG_VERSION};
+use crate::services::config_format::{to_plain_value, ConfigFormat};
+use crate::services::config_manager::{find_config_file, APP_DIR_NAME};
+use crate::services::config_migration::{migrate, unknown_fields, MigrationReport};
+
+/// Prefix of environment variables that override settings, e.g. `GHOSTTIMER_DISPLAY__TRANSPARENCY`
+pub const ENV_PREFIX: &str = "GHOSTTIMER_";
+
+/// Separator between the section and field in an environment variable name
+pub const ENV_SEPARATOR: &str = "__";
+
+/// Where an effective setting came from, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigLayer {
+    Default,
+    System,      // Machine-wide file deployed by an administrator
+    User,        // Per-user file the app saves to
+    Environment, // `GHOSTTIMER_*` variables
+    CommandLine, // `--set key=value`
+}
+
+impl std::fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigLayer::Default => write!(f, "default"),
+            ConfigLayer::System => write!(f, "system file"),
+            ConfigLayer::User => write!(f, "user file"),
+            ConfigLayer::Environment => write!(f, "environment"),
+            ConfigLayer::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/// Directory of the optional machine-wide configuration
+///
+/// `%ProgramData%/GhostTimer` on Windows, `/etc/ghosttimer` elsewhere.
+pub fn system_config_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        std::env::var_os("ProgramData").map(|dir| PathBuf::from(dir).join(APP_DIR_NAME))
+    }
+    #[cfg(not(windows))]
+    {
+        Some(PathBuf::from("/etc").join(APP_DIR_NAME.to_ascii_lowercase()))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum LayerInput {
+    File(PathBuf), // Read on every resolve, so edits are picked up; a missing file is skipped
+    Values(Value),
+}
+
+/// Builds the effective configuration from layered sources
+///
+/// Layers are merged in the order they are added, later ones winning field by
+/// field. Built-in defaults are always the bottom layer.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigResolver {
+    layers: Vec<(ConfigLayer, LayerInput)>,
+    warnings: Vec<String>, // Inputs skipped while building, e.g. a bad environment variable
+}
+
+impl ConfigResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The usual stack: system file, the given user file, `GHOSTTIMER_*` variables and `--set` overrides
+    pub fn standard(user_file: &Path, overrides: &[String]) -> Result<Self, ConfigError> {
+        let mut resolver = Self::new();
+        if let Some(dir) = system_config_dir() {
+            resolver = resolver.with_file(ConfigLayer::System, find_config_file(&dir));
+        }
+        resolver
+            .with_file(ConfigLayer::User, user_file)
+            .with_env(std::env::vars_os())
+            .with_overrides(overrides)
+    }
+
+    /// Add a configuration file in any supported format
+    pub fn with_file(mut self, layer: ConfigLayer, path: impl Into<PathBuf>) -> Self {
+        self.layers.push((layer, LayerInput::File(path.into())));
+        self
+    }
+
+    /// Add settings given as a (possibly partial) configuration value
+    pub fn with_values(mut self, layer: ConfigLayer, values: Value) -> Self {
+        self.layers.push((layer, LayerInput::Values(values)));
+        self
+    }
+
+    /// Add settings from `GHOSTTIMER_<SECTION>__<FIELD>` variables
+    ///
+    /// Variables with the prefix but no `__` are not settings and are ignored.
+    /// A variable that is not Unicode, names an unknown setting or holds an
+    /// invalid value is skipped and listed in the warnings; the others still apply.
+    pub fn with_env(mut self, vars: impl IntoIterator<Item = (OsString, OsString)>) -> Self {
+        let mut assignments = Vec::new();
+        for (name, raw) in vars {
+            let Some(key) = name.to_str().and_then(|name| name.strip_prefix(ENV_PREFIX)) else {
+                continue;
+            };
+            if !key.contains(ENV_SEPARATOR) {
+                continue;
+            }
+            let name = format!("{}{}", ENV_PREFIX, key);
+            match raw.into_string() {
+                Ok(raw) => assignments.push((key.to_ascii_lowercase().replace(ENV_SEPARATOR, "."), raw, name)),
+                Err(_) => self.warnings.push(format!("{}: value is not valid Unicode; ignored", name)),
+            }
+        }
+        // The environment has no order; sort so the result does not depend on it
+        assignments.sort();
+
+        let mut values = Value::Object(Map::new());
+        for (key, raw, name) in assignments {
+            match assignment_values(&[(key, raw)]).and_then(|value| check_alone(&value).map(|_| value)) {
+                Ok(value) => merge_values(&mut values, &value),
+                Err(e) => self.warnings.push(format!("{}: {}; ignored", name, e)),
+            }
+        }
+        self.with_values(ConfigLayer::Environment, values)
+    }
+
+    /// Add `key=value` overrides, e.g. `display.transparency=0.5`
+    pub fn with_overrides(self, overrides: &[String]) -> Result<Self, ConfigError> {
+        let assignments = overrides
+            .iter()
+            .map(|text| {
+                text.split_once('=')
+                    .map(|(key, raw)| (key.trim().to_string(), raw.to_string()))
+                    .ok_or_else(|| ConfigError::InvalidFormat(format!("expected key=value, found `{}`", text)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let values = assignment_values(&assignments)?;
+        Ok(self.with_values(ConfigLayer::CommandLine, values))
+    }
+
+    /// The first user-layer file, which is where changes made in the app are saved
+    pub fn user_file(&self) -> Option<&Path> {
+        self.layers.iter().find_map(|(layer, input)| match (layer, input) {
+            (ConfigLayer::User, LayerInput::File(path)) => Some(path.as_path()),
+            _ => None,
+        })
+    }
+
+    /// Merge every layer and validate the result
+    ///
+    /// Files are read afresh each time. Unknown settings in a file are ignored
+    /// and listed in the warnings.
+    pub fn resolve(&self) -> Result<LayeredConfig, ConfigError> {
+        let defaults = to_plain_value(&Configuration::default())?;
+        let mut merged = defaults.clone();
+        let mut sources = BTreeMap::new();
+        mark_leaves(&defaults, "", ConfigLayer::Default, &mut sources);
+        let mut below_user = None;
+        let mut user = Value::Object(Map::new());
+        let mut warnings = self.warnings.clone();
+
+        for (layer, input) in &self.layers {
+            if *layer >= ConfigLayer::User && below_user.is_none() {
+                below_user = Some(merged.clone());
+            }
+            let values = match input {
+                LayerInput::Values(values) => values.clone(),
+                LayerInput::File(path) => match read_layer_file(path)? {
+                    Some(values) => {
+                        warnings.extend(
+                            unknown_fields(&values, &defaults)
+                                .into_iter()
+                                .map(|field| format!("{}: unknown field `{}` ignored", path.display(), field)),
+                        );
+                        if *layer == ConfigLayer::User {
+                            user_choices(values, &defaults)
+                        } else {
+                            values
+                        }
+                    }
+                    None => continue,
+                },
+            };
+            if *layer == ConfigLayer::User {
+                merge_values(&mut user, &values);
+            }
+            merge_layer(&mut merged, &values, "", *layer, &mut sources);
+        }
+
+        let config: Configuration = serde_json::from_value(merged.clone())
+            .map_err(|e| ConfigError::InvalidFormat(e.to_string()))?;
+        let errors = config.validate_fields();
+        if !errors.is_empty() {
+            return Err(ConfigError::ValidationFailed(errors));
+        }
+        Ok(LayeredConfig {
+            config,
+            warnings,
+            sources,
+            below_user: below_user.unwrap_or(merged),
+            user,
+        })
+    }
+}
+
+/// The effective configuration and where each of its settings came from
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    pub config: Configuration,
+    pub warnings: Vec<String>,
+    sources: BTreeMap<String, ConfigLayer>, // Setting path, e.g. "display.transparency"
+    below_user: Value,                      // Defaults and system file merged
+    user: Value,                            // User file as read
+}
+
+impl LayeredConfig {
+    /// Layer that supplied a setting, by path such as `display.transparency`
+    ///
+    /// Lists such as `notifications.warnings` are a single setting.
+    pub fn source(&self, path: &str) -> Option<ConfigLayer> {
+        self.sources.get(path).copied()
+    }
+
+    /// Every setting path with the layer that supplied it, in path order
+    pub fn sources(&self) -> impl Iterator<Item = (&str, ConfigLayer)> {
+        self.sources.iter().map(|(path, layer)| (path.as_str(), *layer))
+    }
+
+    /// What to save in the user file so that it reproduces `config` on top of the other layers
+    ///
+    /// Settings equal to the defaults and system file are left out, even if the
+    /// user file had them, so a later change to the system file still applies.
+    /// Values that came from the environment or command line are not saved; the
+    /// user file keeps whatever it had for them.
+    pub fn user_values(&self, config: &Configuration) -> Result<Value, ConfigError> {
+        let effective = to_plain_value(&self.config)?;
+        let mut leaves = BTreeMap::new();
+        let current = to_plain_value(config)?;
+        collect_leaves(&current, "", &mut leaves);
+
+        let mut values = Value::Object(Map::new());
+        for (path, value) in leaves {
+            let from_override = self
+                .source(&path)
+                .is_some_and(|layer| layer > ConfigLayer::User)
+                && lookup(&effective, &path) == Some(value);
+            let below = lookup(&self.below_user, &path);
+            let keep = if from_override {
+                lookup(&self.user, &path).filter(|saved| below != Some(*saved))
+            } else if below != Some(value) {
+                Some(value)
+            } else {
+                None
+            };
+            if let Some(keep) = keep {
+                insert_path(&mut values, &path, keep.clone());
+            }
+        }
+        insert_path(&mut values, "version", Value::from(CONFIG_VERSION));
+        Ok(values)
+    }
+}
+
+/// Overlay `over` onto `base`, merging objects and replacing everything else
+pub fn merge_values(base: &mut Value, over: &Value) {
+    match (base, over) {
+        (Value::Object(base), Value::Object(over)) => {
+            for (key, value) in over {
+                match base.get_mut(key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (base, over) => *base = over.clone(),
+    }
+}
+
+/// Upgrade a file that may hold only some settings to `CONFIG_VERSION`
+///
+/// Migration fills in every setting added since the file's version. That keeps
+/// a complete file complete, but would pin those defaults in a partial layer
+/// above the system file, so there the added settings are taken out again.
+pub fn migrate_layer(values: &mut Value) -> Result<MigrationReport, ConfigError> {
+    let before = values.clone();
+    let report = migrate(values)?;
+    let defaults = to_plain_value(&Configuration::default())?;
+    if !is_complete(values, &defaults) {
+        let migrated = values.clone();
+        let mut leaves = BTreeMap::new();
+        collect_leaves(&migrated, "", &mut leaves);
+        for (path, value) in leaves {
+            if path != "version" && lookup(&before, &path).is_none() && lookup(&defaults, &path) == Some(value) {
+                remove_path(values, &path);
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Settings of a user file that the user chose
+///
+/// Before layering the app saved every setting, so a complete file cannot tell
+/// choices from defaults. Its values equal to the built-in defaults are treated
+/// as unset, letting the system file apply beneath them.
+fn user_choices(mut values: Value, defaults: &Value) -> Value {
+    if !is_complete(&values, defaults) {
+        return values;
+    }
+    let mut leaves = BTreeMap::new();
+    collect_leaves(defaults, "", &mut leaves);
+    for (path, default) in leaves {
+        if lookup(&values, &path) == Some(default) {
+            remove_path(&mut values, &path);
+        }
+    }
+    values
+}
+
+/// Whether a file sets every setting, as full saves before layering did
+fn is_complete(values: &Value, defaults: &Value) -> bool {
+    let mut leaves = BTreeMap::new();
+    collect_leaves(defaults, "", &mut leaves);
+    leaves.keys().all(|path| lookup(values, path).is_some())
+}
+
+/// Whether a single environment setting is valid on top of the defaults
+fn check_alone(values: &Value) -> Result<(), ConfigError> {
+    let mut merged = to_plain_value(&Configuration::default())?;
+    merge_values(&mut merged, values);
+    let config: Configuration = serde_json::from_value(merged).map_err(|e| ConfigError::InvalidFormat(e.to_string()))?;
+    let errors = config.validate_fields();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ConfigError::ValidationFailed(errors))
+    }
+}
+
+/// Read and migrate a layer file, or `None` if it does not exist
+///
+/// Only files that state a version are migrated; see `migrate_layer`.
+fn read_layer_file(path: &Path) -> Result<Option<Value>, ConfigError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(ConfigError::InvalidFormat(format!("{}: {}", path.display(), e))),
+    };
+    let format = ConfigFormat::from_path(path).unwrap_or(ConfigFormat::Json);
+    let mut values = format
+        .parse(&text)
+        .map_err(|e| ConfigError::InvalidFormat(format!("{}: {}", path.display(), e)))?;
+    if !values.is_object() {
+        return Err(ConfigError::InvalidFormat(format!("{}: expected a table of settings", path.display())));
+    }
+    if values.get("version").is_some() {
+        migrate_layer(&mut values)?;
+    }
+    Ok(Some(values))
+}
+
+/// Build a partial configuration from `section.field` assignments of raw text
+///
+/// Text goes into string settings as is; anything else is read as JSON, so
+/// `true`, `0.5` and `[100, 200]` work as expected.
+fn assignment_values(assignments: &[(String, String)]) -> Result<Value, ConfigError> {
+    let template = to_plain_value(&Configuration::default())?;
+    let mut values = Value::Object(Map::new());
+    for (key, raw) in assignments {
+        let existing = lookup(&template, key)
+            .filter(|_| key != "version")
+            .ok_or_else(|| ConfigError::InvalidFormat(format!("unknown setting `{}`", key)))?;
+        let value = match existing {
+            Value::String(_) => Value::String(raw.clone()),
+            _ => serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.clone())),
+        };
+        insert_path(&mut values, key, value);
+    }
+    Ok(values)
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Settings are object fields; lists and scalars are leaves
+fn collect_leaves<'a>(value: &'a Value, path: &str, leaves: &mut BTreeMap<String, &'a Value>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                collect_leaves(field, &join(path, key), leaves);
+            }
+        }
+        leaf => {
+            leaves.insert(path.to_string(), leaf);
+        }
+    }
+}
+
+fn mark_leaves(value: &Value, path: &str, layer: ConfigLayer, sources: &mut BTreeMap<String, ConfigLayer>) {
+    let mut leaves = BTreeMap::new();
+    collect_leaves(value, path, &mut leaves);
+    for path in leaves.into_keys() {
+        sources.insert(path, layer);
+    }
+}
+
+/// `merge_values` that also records which layer each replaced setting now comes from
+fn merge_layer(
+    base: &mut Value,
+    over: &Value,
+    path: &str,
+    layer: ConfigLayer,
+    sources: &mut BTreeMap<String, ConfigLayer>,
+) {
+    match (base, over) {
+        (Value::Object(base), Value::Object(over)) => {
+            for (key, value) in over {
+                let child = join(path, key);
+                match base.get_mut(key) {
+                    Some(existing) => merge_layer(existing, value, &child, layer, sources),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                        mark_leaves(value, &child, layer, sources);
+                    }
+                }
+            }
+        }
+        (base, over) => {
+            let nested = format!("{}.", path);
+            sources.retain(|key, _| !key.starts_with(&nested));
+            *base = over.clone();
+            mark_leaves(over, path, layer, sources);
+        }
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |value, key| value.get(key))
+}
+
+/// Remove a setting, and any sections left empty by its removal
+fn remove_path(value: &mut Value, path: &str) {
+    let Some((key, rest)) = path.split_once('.') else {
+        if let Value::Object(fields) = value {
+            fields.remove(path);
+        }
+        return;
+    };
+    let Value::Object(fields) = value else {
+        return;
+    };
+    if let Some(child) = fields.get_mut(key) {
+        remove_path(child, rest);
+        if child.as_object().is_some_and(|child| child.is_empty()) {
+            fields.remove(key);
+        }
+    }
+}
+
+fn insert_path(value: &mut Value, path: &str, leaf: Value) {
+    let mut current = value;
+    let mut keys = path.split('.').peekable();
+    while let Some(key) = keys.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let Value::Object(fields) = current else {
+            unreachable!()
+        };
+        if keys.peek().is_none() {
+            fields.insert(key.to_string(), leaf);
+            return;
+        }
+        current = fields.entry(key.to_string()).or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ghost_timer_layers_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(OsString, OsString)> {
+        pairs.iter().map(|(name, value)| (OsString::from(name), OsString::from(value))).collect()
+    }
+
+    #[test]
+    fn test_later_layers_win_and_report_their_source() {
+        let dir = temp_dir("precedence");
+        let system = dir.join("system.toml");
+        let user = dir.join("config.json");
+        fs::write(&system, "[display]\ntransparency = 0.4\nshow_controls = false\n\n[behavior]\ntime_adjust_step = \"5m\"\n").unwrap();
+        fs::write(&user, r#"{ "display": { "transparency": 0.6 }, "hotkeys": { "reset": null } }"#).unwrap();
+
+        let layered = ConfigResolver::new()
+            .with_file(ConfigLayer::System, &system)
+            .with_file(ConfigLayer::User, &user)
+            .with_env(vars(&[
+                ("GHOSTTIMER_DISPLAY__HOVER_TRANSPARENCY", "0.9"),
+                ("GHOSTTIMER_BEHAVIOR__TIME_ADJUST_STEP", "45s"),
+                ("GHOSTTIMER_HOME", "ignored"),
+                ("PATH", "/usr/bin"),
+            ]))
+            .with_overrides(&["display.position=[10, 20]".to_string(), "behavior.overtime_enabled=true".to_string()])
+            .unwrap()
+            .resolve()
+            .unwrap();
+
+        let config = &layered.config;
+        assert_eq!(config.display.transparency, 0.6);
+        assert!(!config.display.show_controls);
+        assert_eq!(config.display.hover_transparency, 0.9);
+        assert_eq!(config.behavior.time_adjust_step, "45s");
+        assert_eq!(config.display.position, (10, 20));
+        assert!(config.behavior.overtime_enabled);
+        assert_eq!(config.hotkeys.reset, None);
+
+        assert_eq!(layered.source("display.transparency"), Some(ConfigLayer::User));
+        assert_eq!(layered.source("display.show_controls"), Some(ConfigLayer::System));
+        assert_eq!(layered.source("display.hover_transparency"), Some(ConfigLayer::Environment));
+        assert_eq!(layered.source("behavior.time_adjust_step"), Some(ConfigLayer::Environment));
+        assert_eq!(layered.source("display.position"), Some(ConfigLayer::CommandLine));
+        assert_eq!(layered.source("hotkeys.reset"), Some(ConfigLayer::User));
+        assert_eq!(layered.source("pomodoro.work_minutes"), Some(ConfigLayer::Default));
+        assert_eq!(layered.source("display.nonexistent"), None);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_missing_files_are_skipped_and_bad_overrides_rejected() {
+        let dir = temp_dir("missing");
+        let layered = ConfigResolver::new()
+            .with_file(ConfigLayer::System, dir.join("nope.json"))
+            .with_file(ConfigLayer::User, dir.join("config.json"))
+            .resolve()
+            .unwrap();
+        assert!(layered.sources().all(|(_, layer)| layer == ConfigLayer::Default));
+
+        let unknown = ConfigResolver::new().with_overrides(&["display.font=Consolas".to_string()]);
+        assert!(matches!(unknown, Err(ConfigError::InvalidFormat(_))));
+        let malformed = ConfigResolver::new().with_overrides(&["display.transparency".to_string()]);
+        assert!(matches!(malformed, Err(ConfigError::InvalidFormat(_))));
+
+        // Values are checked once merged, naming the offending setting
+        let invalid = ConfigResolver::new()
+            .with_overrides(&["display.transparency=2".to_string()])
+            .unwrap()
+            .resolve();
+        let Err(ConfigError::ValidationFailed(errors)) = invalid else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(errors[0].path, "display.transparency");
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_user_values_hold_only_user_changes() {
+        let dir = temp_dir("user_values");
+        let system = dir.join("system.json");
+        let user = dir.join("config.json");
+        fs::write(&system, r#"{ "display": { "transparency": 0.4 }, "behavior": { "time_adjust_step": "5m" } }"#).unwrap();
+        fs::write(&user, r#"{ "behavior": { "time_adjust_step": "5m" }, "pomodoro": { "work_minutes": 50 } }"#).unwrap();
+
+        let resolver = ConfigResolver::new()
+            .with_file(ConfigLayer::System, &system)
+            .with_file(ConfigLayer::User, &user)
+            .with_overrides(&["display.hover_transparency=0.95".to_string()])
+            .unwrap();
+        let layered = resolver.resolve().unwrap();
+
+        let mut config = layered.config.clone();
+        config.display.position = (300, 200);
+        let values = layered.user_values(&config).unwrap();
+
+        assert_eq!(lookup(&values, "display.position"), Some(&serde_json::from_str("[300, 200]").unwrap()));
+        assert_eq!(lookup(&values, "pomodoro.work_minutes"), Some(&Value::from(50)));
+        // Set by the user but the same as the system file, so it follows the system file from now on
+        assert_eq!(lookup(&values, "behavior.time_adjust_step"), None);
+        // From the system file and the command line, not the user
+        assert_eq!(lookup(&values, "display.transparency"), None);
+        assert_eq!(lookup(&values, "display.hover_transparency"), None);
+        assert_eq!(lookup(&values, "version"), Some(&Value::from(CONFIG_VERSION)));
+
+        // Saved and resolved again, the same configuration comes back
+        fs::write(&user, serde_json::to_string_pretty(&values).unwrap()).unwrap();
+        let reloaded = resolver.resolve().unwrap();
+        assert_eq!(reloaded.config.display.position, (300, 200));
+        assert_eq!(reloaded.config.display.transparency, 0.4);
+        assert_eq!(reloaded.source("display.transparency"), Some(ConfigLayer::System));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_bad_environment_variables_are_skipped() {
+        let mut env = vars(&[
+            ("GHOSTTIMER_DISPLAY__FONT", "Consolas"),
+            ("GHOSTTIMER_DISPLAY__TRANSPARENCY", "7"),
+            ("GHOSTTIMER_PomoDoro__WORK_MINUTES", "45"),
+        ]);
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStringExt;
+            env.push((OsString::from("GHOSTTIMER_BEHAVIOR__TIME_ADJUST_STEP"), OsString::from_vec(vec![0x35, 0xff])));
+            env.push((OsString::from_vec(vec![0x47, 0xff]), OsString::from("not ours")));
+        }
+
+        let layered = ConfigResolver::new().with_env(env).resolve().unwrap();
+        assert_eq!(layered.config.pomodoro.work_minutes, 45);
+        assert_eq!(layered.config.display.transparency, Configuration::default().display.transparency);
+        assert_eq!(layered.source("display.transparency"), Some(ConfigLayer::Default));
+        assert!(layered.warnings.iter().any(|w| w.starts_with("GHOSTTIMER_DISPLAY__FONT: ")));
+        assert!(layered.warnings.iter().any(|w| w.starts_with("GHOSTTIMER_DISPLAY__TRANSPARENCY: ")));
+        #[cfg(unix)]
+        assert!(layered.warnings.iter().any(|w| w.contains("not valid Unicode")));
+    }
+
+    #[test]
+    fn test_legacy_full_user_file_lets_system_file_apply() {
+        let dir = temp_dir("legacy");
+        let system = dir.join("system.toml");
+        let user = dir.join("config.json");
+        fs::write(&system, "[display]\ntransparency = 0.4\n\n[behavior]\nconfig_backups = 3\n").unwrap();
+        // Saved by a release that wrote every setting; only the position was changed
+        let legacy = include_str!("../../tests/fixtures/config/v1.0-baseline.json");
+        fs::write(&user, legacy.replace("[100, 100]", "[640, 20]")).unwrap();
+
+        let resolver = ConfigResolver::new()
+            .with_file(ConfigLayer::System, &system)
+            .with_file(ConfigLayer::User, &user);
+        let layered = resolver.resolve().unwrap();
+        assert_eq!(layered.config.display.transparency, 0.4);
+        assert_eq!(layered.config.behavior.config_backups, 3);
+        assert_eq!(layered.config.display.position, (640, 20));
+        assert_eq!(layered.source("display.transparency"), Some(ConfigLayer::System));
+
+        // The first save keeps only what differs from the layers below
+        let values = layered.user_values(&layered.config).unwrap();
+        let mut saved = BTreeMap::new();
+        collect_leaves(&values, "", &mut saved);
+        assert_eq!(saved.keys().map(String::as_str).collect::<Vec<_>>(), vec!["display.position", "version"]);
+
+        // A partial file from an older version does not gain the settings added since
+        fs::write(&user, r#"{ "version": "2.0", "pomodoro": { "work_minutes": 50 } }"#).unwrap();
+        let layered = resolver.resolve().unwrap();
+        assert_eq!(layered.config.behavior.config_backups, 3);
+        assert_eq!(layered.config.pomodoro.work_minutes, 50);
+        assert_eq!(layered.source("behavior.config_backups"), Some(ConfigLayer::System));
+        let _ = fs::remove_dir_all(dir);
+    }
+}
\ No newline at end of file
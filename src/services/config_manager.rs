@@ -1,10 +1,952 @@
-// Configuration manager service implementation - placeholder  
-// This will be implemented in the next phase
+// Configuration persistence in the platform config directory
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 
-pub struct ConfigManagerImpl;
+use serde_json::Value;
+
+use crate::models::config::{ConfigError, Configuration, ValidationError};
+use crate::services::config_format::{to_plain_value, ConfigFormat};
+use crate::services::config_layers::{merge_values, migrate_layer};
+use crate::services::config_migration::{migrate, unknown_fields, MigrationReport};
+use crate::services::config_salvage::{salvage, ResetField};
+use crate::services::sound_player::{check_decodable, SoundError};
+
+/// Directory name under the platform config directory
+pub const APP_DIR_NAME: &str = "GhostTimer";
+
+/// File name of the configuration inside the GhostTimer config directory
+///
+/// `config.toml` and `config.yaml` are used instead when one of them exists.
+pub const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Directory next to the configuration file that holds timestamped backups
+pub const BACKUP_DIR_NAME: &str = "backups";
+
+/// How often `ConfigWatcher` looks at the configuration file for outside edits
+pub const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Platform config directory for GhostTimer, e.g. `%APPDATA%/GhostTimer`
+pub fn app_config_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(APP_DIR_NAME)
+}
+
+/// The configuration file in a directory, in whichever supported format exists
+///
+/// When several exist, the first in `ConfigFormat::ALL` order is used and the
+/// others are reported, since edits to them would silently have no effect.
+pub fn find_config_file(dir: &Path) -> PathBuf {
+    let files = config_files(dir);
+    let Some((found, ignored)) = files.split_first() else {
+        return dir.join(CONFIG_FILE_NAME);
+    };
+    for path in ignored {
+        eprintln!("Configuration: ignoring {}; using {}", path.display(), found.display());
+    }
+    found.clone()
+}
+
+/// Every configuration file present in a directory, in `ConfigFormat::ALL` order
+pub fn config_files(dir: &Path) -> Vec<PathBuf> {
+    ConfigFormat::ALL
+        .iter()
+        .map(|format| dir.join(Path::new(CONFIG_FILE_NAME).with_extension(format.extension())))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// Configuration persistence interface
+pub trait ConfigManager {
+    /// Load configuration from disk; a missing file yields the defaults
+    fn load(&self) -> Result<Configuration, ConfigError>;
+
+    /// Validate and save configuration, creating the directory if needed
+    fn save(&self, config: &Configuration) -> Result<(), ConfigError>;
+
+    /// Validate configuration values
+    fn validate(&self, config: &Configuration) -> Vec<ValidationError>;
+
+    /// Configuration file path
+    fn config_path(&self) -> PathBuf;
+
+    /// Check if the configuration file exists
+    fn exists(&self) -> bool;
+
+    /// Copy the current configuration file into the timestamped backups
+    fn backup(&self) -> Result<(), ConfigError>;
+}
+
+/// A timestamped copy of the configuration file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigBackup {
+    pub name: String, // e.g. "config-20240131-093000-125.json", in the format of the file it copies
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>,
+}
+
+/// What happened while loading the configuration file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadReport {
+    pub migration: MigrationReport,
+    pub unreadable: Option<String>,      // Parse error when the file was not valid JSON
+    pub restored_backup: Option<String>, // Backup used in place of an unreadable file
+    pub reset: Vec<ResetField>,          // Settings that were invalid and replaced with defaults
+    pub quarantined: Option<PathBuf>,    // Copy of the damaged file
+}
+
+impl LoadReport {
+    /// Whether the file was damaged and replaced
+    pub fn recovered(&self) -> bool {
+        self.unreadable.is_some() || !self.reset.is_empty()
+    }
+
+    /// One line per thing the user should know about, for display in the UI
+    pub fn notices(&self) -> Vec<String> {
+        let mut notices = Vec::new();
+        if let Some(error) = &self.unreadable {
+            notices.push(match &self.restored_backup {
+                Some(name) => format!("Configuration could not be read ({}); restored backup {}", error, name),
+                None => format!("Configuration could not be read ({}); using defaults", error),
+            });
+        }
+        notices.extend(self.reset.iter().map(|field| field.to_string()));
+        if let Some(path) = &self.quarantined {
+            notices.push(format!("Damaged file kept as {}", path.display()));
+        }
+        notices
+    }
+
+    /// Write upgrades, warnings and notices to stderr
+    pub fn log(&self) {
+        for step in &self.migration.applied {
+            eprintln!("Upgraded configuration {}", step);
+        }
+        for warning in &self.migration.warnings {
+            eprintln!("Configuration: {}", warning);
+        }
+        for notice in self.notices() {
+            eprintln!("Configuration: {}", notice);
+        }
+    }
+}
+
+/// Reads and writes `config.json`, or its TOML or YAML equivalent
+#[derive(Debug, Clone)]
+pub struct ConfigManagerImpl {
+    path: PathBuf,
+    format: ConfigFormat, // From the file extension
+}
 
 impl ConfigManagerImpl {
+    /// Manager for the platform config directory, e.g. `%APPDATA%/GhostTimer/config.json`
     pub fn new() -> Self {
-        Self
+        Self::with_path(find_config_file(&app_config_dir()))
+    }
+
+    /// Manager for an explicit path; files without a known extension are JSON
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let format = ConfigFormat::from_path(&path).unwrap_or(ConfigFormat::Json);
+        Self { path, format }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn format(&self) -> ConfigFormat {
+        self.format
+    }
+
+    /// Directory holding timestamped backups
+    pub fn backup_dir(&self) -> PathBuf {
+        self.path
+            .parent()
+            .map(|dir| dir.join(BACKUP_DIR_NAME))
+            .unwrap_or_else(|| PathBuf::from(BACKUP_DIR_NAME))
+    }
+
+    /// Backups, newest first
+    pub fn list_backups(&self) -> Result<Vec<ConfigBackup>, ConfigError> {
+        let entries = match fs::read_dir(self.backup_dir()) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(ConfigError::InvalidFormat(e.to_string())),
+        };
+
+        let mut backups: Vec<ConfigBackup> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let is_backup = name.starts_with("config-") && ConfigFormat::from_path(&entry.path()).is_some();
+                is_backup.then(|| ConfigBackup {
+                    modified: entry.metadata().and_then(|meta| meta.modified()).ok(),
+                    path: entry.path(),
+                    name,
+                })
+            })
+            .collect();
+        // Names embed a fixed-width timestamp, so they sort chronologically
+        backups.sort_by(|a, b| backup_stem(&b.name).cmp(backup_stem(&a.name)));
+        Ok(backups)
+    }
+
+    /// Find a backup by file name or by position in `list_backups` (1 = newest)
+    pub fn find_backup(&self, name_or_index: &str) -> Result<ConfigBackup, ConfigError> {
+        let backups = self.list_backups()?;
+        let found = match name_or_index.parse::<usize>() {
+            Ok(index) if index >= 1 => backups.into_iter().nth(index - 1),
+            _ => backups.into_iter().find(|backup| backup.name == name_or_index),
+        };
+        found.ok_or(ConfigError::FileNotFound)
+    }
+
+    /// Replace the configuration file with a backup, after checking that it loads and validates
+    ///
+    /// The file being replaced is backed up first, so a restore can itself be undone.
+    pub fn restore_backup(&self, name_or_index: &str) -> Result<Configuration, ConfigError> {
+        let backup = self.find_backup(name_or_index)?;
+        let (config, value) = Self::read_valid(&backup.path)?;
+
+        let previous = fs::read_to_string(&self.path).ok();
+        if previous.is_some() {
+            self.create_backup()?;
+        }
+        self.write_atomic(&self.format.render(&value, previous.as_deref())?)?;
+        Ok(config)
+    }
+
+    /// Read the configuration file without repairing or rewriting it
+    ///
+    /// Unlike `load`, a damaged file is an error rather than something to salvage.
+    pub fn load_strict(&self) -> Result<Configuration, ConfigError> {
+        Self::read_valid(&self.path).map(|(config, _)| config)
+    }
+
+    /// Watcher that reports edits made to the file by other programs
+    pub fn watcher(&self) -> ConfigWatcher {
+        ConfigWatcher::new(self.clone())
+    }
+
+    /// Read a configuration file strictly: it must parse, migrate, deserialize and validate
+    ///
+    /// Settings the file leaves out take their defaults, so partial files saved
+    /// under a layered configuration can be read and restored too.
+    fn read_valid(path: &Path) -> Result<(Configuration, Value), ConfigError> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| ConfigError::InvalidFormat(e.to_string()))?;
+        let format = ConfigFormat::from_path(path).unwrap_or(ConfigFormat::Json);
+        let mut value = format.parse(&text)?;
+        migrate(&mut value)?;
+
+        let mut complete = to_plain_value(&Configuration::default())?;
+        merge_values(&mut complete, &value);
+        let config: Configuration = serde_json::from_value(complete)
+            .map_err(|e| ConfigError::InvalidFormat(e.to_string()))?;
+        let errors = config.validate_fields();
+        if !errors.is_empty() {
+            return Err(ConfigError::ValidationFailed(errors));
+        }
+        Ok((config, value))
+    }
+
+    /// Write settings as given, then keep a backup as `save` does
+    ///
+    /// Used for the user layer of a layered configuration, which only holds the
+    /// settings the user changed and is not a valid configuration on its own.
+    /// Saves that only move the window are not backed up, so dragging the
+    /// widget around does not push real changes out of the backup rotation.
+    pub fn save_values(&self, values: &Value, backups: usize) -> Result<(), ConfigError> {
+        let previous = fs::read_to_string(&self.path).ok();
+        let moved_only = previous
+            .as_deref()
+            .and_then(|text| self.format.parse(text).ok())
+            .is_some_and(|old| without_position(&old) == without_position(values));
+        self.write_atomic(&self.format.render(values, previous.as_deref())?)?;
+
+        // The save itself succeeded; a failed backup is only worth a log line
+        if backups > 0 && !moved_only {
+            if let Err(e) = self.create_backup().and_then(|_| self.prune_backups(backups)) {
+                eprintln!("Failed to back up configuration: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rewrite the configuration in another format, e.g. `config.json` to `config.toml`
+    ///
+    /// The current file is backed up and then removed, so the new one is the only
+    /// configuration file left. Returns a manager for the new file.
+    pub fn convert(&self, format: ConfigFormat) -> Result<ConfigManagerImpl, ConfigError> {
+        if format == self.format {
+            return Ok(self.clone());
+        }
+        let target = Self::with_path(self.path.with_extension(format.extension()));
+        // Converting next to another config file would leave the choice between them to lookup order
+        let dir = self.path.parent().unwrap_or(Path::new("."));
+        if let Some(other) = config_files(dir).into_iter().find(|path| *path != self.path) {
+            return Err(ConfigError::WriteError(format!("{} already exists", other.display())));
+        }
+        let (_, value) = Self::read_valid(&self.path)?;
+
+        self.create_backup()?;
+        target.write_atomic(&format.render(&value, None)?)?;
+        fs::remove_file(&self.path).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        Ok(target)
+    }
+
+    /// Copy the configuration file into the backup directory under a timestamped name
+    ///
+    /// Nothing is written when the newest backup already has the same contents.
+    fn create_backup(&self) -> Result<Option<PathBuf>, ConfigError> {
+        let contents = fs::read(&self.path).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        if let Some(newest) = self.list_backups()?.first() {
+            if fs::read(&newest.path).ok().as_deref() == Some(contents.as_slice()) {
+                return Ok(None);
+            }
+        }
+
+        let dir = self.backup_dir();
+        fs::create_dir_all(&dir).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S-%3f").to_string();
+        let ext = self.format.extension();
+        let mut path = dir.join(format!("config-{}.{}", stamp, ext));
+        let mut suffix = 1;
+        while path.exists() {
+            // Saved twice within a millisecond
+            path = dir.join(format!("config-{}-{}.{}", stamp, suffix, ext));
+            suffix += 1;
+        }
+        fs::write(&path, contents).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        Ok(Some(path))
+    }
+
+    /// Delete the oldest backups beyond the given count
+    fn prune_backups(&self, keep: usize) -> Result<(), ConfigError> {
+        for backup in self.list_backups()?.into_iter().skip(keep) {
+            fs::remove_file(&backup.path).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Copy of the file as it was before being upgraded from the given schema version
+    pub fn migration_backup_path(&self, version: &str) -> PathBuf {
+        self.path.with_extension(format!("v{}.{}.bak", version, self.format.extension()))
+    }
+
+    /// Copy of a damaged configuration file, e.g. `config.corrupt-20240131-093000.json`
+    fn quarantine_path(&self) -> PathBuf {
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+        let ext = self.format.extension();
+        let mut path = self.path.with_extension(format!("corrupt-{}.{}", stamp, ext));
+        let mut suffix = 1;
+        while path.exists() {
+            path = self.path.with_extension(format!("corrupt-{}-{}.{}", stamp, suffix, ext));
+            suffix += 1;
+        }
+        path
+    }
+
+    /// Load configuration, upgrading older files to the current schema
+    ///
+    /// An upgraded file is written back after the original is copied to
+    /// `migration_backup_path`. Settings the file leaves out take their
+    /// defaults; unknown fields are ignored and listed in the migration warnings.
+    ///
+    /// A damaged file does not fail the load. Invalid settings are reset to
+    /// their defaults while every valid one is kept; a file that does not parse
+    /// at all is replaced by the newest backup that loads, or by the defaults.
+    /// Either way the original is kept at a `config.corrupt-*.json` path and the
+    /// report lists what changed.
+    pub fn load_with_report(&self) -> Result<(Configuration, LoadReport), ConfigError> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok((Configuration::default(), LoadReport::default()))
+            }
+            Err(e) => return Err(ConfigError::InvalidFormat(e.to_string())),
+        };
+        let mut report = LoadReport::default();
+        let mut value = match self.format.parse(&text) {
+            Ok(value) => value,
+            Err(e) => {
+                report.unreadable = Some(e.to_string());
+                let config = self.newest_valid_backup(&mut report);
+                self.replace_damaged(&config, &mut report);
+                return Ok((config, report));
+            }
+        };
+
+        let template = to_plain_value(&Configuration::default())?;
+        let migrated = migrate_layer(&mut value);
+        // Settings the file leaves out take their defaults
+        let mut complete = template.clone();
+        merge_values(&mut complete, &value);
+        let strict = match migrated {
+            Ok(migration) => {
+                report.migration = migration;
+                serde_json::from_value::<Configuration>(complete.clone())
+                    .ok()
+                    .filter(|config| config.validate_fields().is_empty())
+            }
+            Err(e) => {
+                report.migration.warnings.push(e.to_string());
+                None
+            }
+        };
+        report.migration.warnings.extend(
+            unknown_fields(&value, &template)
+                .into_iter()
+                .map(|field| format!("unknown field `{}` ignored", field)),
+        );
+
+        let Some(config) = strict else {
+            let (config, reset) = salvage(&complete);
+            report.reset = reset;
+            self.replace_damaged(&config, &mut report);
+            return Ok((config, report));
+        };
+        // Playback falls back to the system sound, so the setting is kept for the user to fix
+        if let Some(e) = undecodable_sound_file(&config) {
+            report.migration.warnings.push(format!("{}; the system sound plays instead", e));
+        }
+
+        if report.migration.upgraded() {
+            fs::copy(&self.path, self.migration_backup_path(&report.migration.from_version))
+                .map_err(|e| ConfigError::WriteError(e.to_string()))?;
+            self.write_atomic(&self.format.render(&value, Some(&text))?)?;
+        }
+        Ok((config, report))
+    }
+
+    /// The newest backup that loads cleanly, or the defaults if there is none
+    fn newest_valid_backup(&self, report: &mut LoadReport) -> Configuration {
+        for backup in self.list_backups().unwrap_or_default() {
+            if let Ok((config, _)) = Self::read_valid(&backup.path) {
+                report.restored_backup = Some(backup.name);
+                return config;
+            }
+        }
+        Configuration::default()
+    }
+
+    /// Keep a copy of the damaged file, then save the recovered configuration over it
+    ///
+    /// If the copy cannot be made the damaged file is left alone, so nothing is lost.
+    fn replace_damaged(&self, config: &Configuration, report: &mut LoadReport) {
+        let quarantine = self.quarantine_path();
+        if let Err(e) = fs::copy(&self.path, &quarantine) {
+            eprintln!("Cannot keep a copy of the damaged configuration: {}", e);
+            return;
+        }
+        report.quarantined = Some(quarantine);
+        if let Err(e) = self.save(config) {
+            eprintln!("Failed to save recovered configuration: {}", e);
+        }
+    }
+
+    /// Write a temporary file first so a crash never leaves a partial config
+    fn write_atomic(&self, contents: &str) -> Result<(), ConfigError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        }
+        let temp_path = self.path.with_extension(format!("{}.tmp", self.format.extension()));
+        fs::write(&temp_path, contents).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        fs::rename(&temp_path, &self.path).map_err(|e| ConfigError::WriteError(e.to_string()))
+    }
+}
+
+/// Size and modification time, enough to notice an edit without reading the file
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl FileStamp {
+    fn of(path: &Path) -> Option<Self> {
+        let meta = fs::metadata(path).ok()?;
+        Some(Self {
+            modified: meta.modified().ok(),
+            len: meta.len(),
+        })
+    }
+}
+
+/// Notices when the configuration file is edited outside the app
+///
+/// The file is polled rather than watched through OS notifications, so edits on
+/// any platform and by any editor are seen within one poll interval. Writes the
+/// app makes itself are passed to `acknowledge` so they are not reported back.
+#[derive(Debug, Clone)]
+pub struct ConfigWatcher {
+    manager: ConfigManagerImpl,
+    seen: Option<FileStamp>,
+    last_poll: Option<Instant>,
+    interval: Duration,
+}
+
+impl ConfigWatcher {
+    pub fn new(manager: ConfigManagerImpl) -> Self {
+        Self {
+            seen: FileStamp::of(manager.path()),
+            manager,
+            last_poll: None,
+            interval: CONFIG_POLL_INTERVAL,
+        }
+    }
+
+    /// Check the file at most once per interval
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Treat the file as it is now as already applied
+    pub fn acknowledge(&mut self) {
+        self.seen = FileStamp::of(self.manager.path());
+    }
+
+    /// Whether the file changed since the last poll
+    ///
+    /// A deleted file is not a change; the current configuration stays in effect
+    /// and is written again on the next save.
+    pub fn changed(&mut self, now: Instant) -> bool {
+        if self.last_poll.is_some_and(|last| now.saturating_duration_since(last) < self.interval) {
+            return false;
+        }
+        self.last_poll = Some(now);
+
+        let stamp = FileStamp::of(self.manager.path());
+        if stamp.is_none() || stamp == self.seen {
+            return false;
+        }
+        self.seen = stamp;
+        true
+    }
+
+    /// The edited configuration, or why it was rejected, if the file changed since the last poll
+    pub fn poll(&mut self, now: Instant) -> Option<Result<Configuration, ConfigError>> {
+        self.changed(now).then(|| self.manager.load_strict())
+    }
+}
+
+/// Backup name without its extension, so `-1` suffixes sort after the plain name
+fn backup_stem(name: &str) -> &str {
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+impl Default for ConfigManagerImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfigManager for ConfigManagerImpl {
+    fn load(&self) -> Result<Configuration, ConfigError> {
+        let (config, report) = self.load_with_report()?;
+        report.log();
+        Ok(config)
+    }
+
+    fn save(&self, config: &Configuration) -> Result<(), ConfigError> {
+        let errors = config.validate_fields();
+        if !errors.is_empty() {
+            return Err(ConfigError::ValidationFailed(errors));
+        }
+
+        self.save_values(&to_plain_value(config)?, config.behavior.config_backups as usize)
+    }
+
+    fn validate(&self, config: &Configuration) -> Vec<ValidationError> {
+        let mut errors = config.validate();
+        if let (Some(_), Some(sound_file)) = (undecodable_sound_file(config), &config.notifications.sound_file) {
+            errors.push(ValidationError::InvalidSoundFile(sound_file.clone()));
+        }
+        errors
+    }
+
+    fn config_path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    fn exists(&self) -> bool {
+        self.path.is_file()
+    }
+
+    fn backup(&self) -> Result<(), ConfigError> {
+        if !self.exists() {
+            return Err(ConfigError::FileNotFound);
+        }
+        self.create_backup().map(|_| ())
+    }
+}
+
+/// Settings apart from the window position
+fn without_position(values: &Value) -> Value {
+    let mut values = values.clone();
+    if let Some(display) = values.get_mut("display").and_then(Value::as_object_mut) {
+        display.remove("position");
+    }
+    values
+}
+
+/// Why the configured sound file cannot be played, if one is set and exists but does not decode
+///
+/// Missing files are already rejected by `NotificationConfig::validate_fields`.
+fn undecodable_sound_file(config: &Configuration) -> Option<SoundError> {
+    let sound_file = config.notifications.sound_file.as_deref().filter(|path| !path.is_empty())?;
+    check_decodable(Path::new(sound_file)).err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_manager(name: &str) -> (PathBuf, ConfigManagerImpl) {
+        let dir = std::env::temp_dir().join(format!("ghost_timer_config_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let manager = ConfigManagerImpl::with_path(dir.join(CONFIG_FILE_NAME));
+        (dir, manager)
+    }
+
+    #[test]
+    fn test_default_path_is_in_app_dir() {
+        let path = ConfigManagerImpl::new().config_path();
+        assert!(path.ends_with(Path::new(APP_DIR_NAME).join(CONFIG_FILE_NAME)));
+    }
+
+    #[test]
+    fn test_missing_file_loads_defaults() {
+        let (dir, manager) = temp_manager("missing");
+        assert!(!manager.exists());
+        assert_eq!(manager.load().unwrap().version, Configuration::default().version);
+        assert_eq!(manager.backup(), Err(ConfigError::FileNotFound));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_save_load_and_backup() {
+        let (dir, manager) = temp_manager("round_trip");
+        let mut config = Configuration::default();
+        config.display.transparency = 0.5;
+        config.display.position = (200, 300);
+
+        manager.save(&config).unwrap();
+        assert!(manager.exists());
+        assert!(!manager.path().with_extension("json.tmp").exists());
+
+        let loaded = manager.load().unwrap();
+        assert_eq!(loaded.display.transparency, 0.5);
+        assert_eq!(loaded.display.position, (200, 300));
+
+        // Saving also kept a backup; an unchanged file is not copied again
+        assert_eq!(manager.list_backups().unwrap().len(), 1);
+        manager.backup().unwrap();
+        assert_eq!(manager.list_backups().unwrap().len(), 1);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_backups_rotate_and_restore() {
+        let (dir, manager) = temp_manager("backups");
+        let mut config = Configuration::default();
+        config.behavior.config_backups = 3;
+        for minutes in 20..25 {
+            config.pomodoro.work_minutes = minutes;
+            manager.save(&config).unwrap();
+        }
+
+        // Only the newest three are kept, newest first
+        let backups = manager.list_backups().unwrap();
+        assert_eq!(backups.len(), 3);
+        assert!(backup_stem(&backups[0].name) > backup_stem(&backups[1].name));
+        assert_eq!(manager.find_backup("1").unwrap(), backups[0]);
+        assert_eq!(manager.find_backup(&backups[2].name).unwrap(), backups[2]);
+        assert_eq!(manager.find_backup("7"), Err(ConfigError::FileNotFound));
+
+        let restored = manager.restore_backup("3").unwrap();
+        assert_eq!(restored.pomodoro.work_minutes, 22);
+        assert_eq!(manager.load().unwrap().pomodoro.work_minutes, 22);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_window_moves_are_not_backed_up() {
+        let (dir, manager) = temp_manager("moves");
+        let mut config = Configuration::default();
+        manager.save(&config).unwrap();
+        for x in 1..5 {
+            config.display.position = (x * 100, 0);
+            manager.save(&config).unwrap();
+        }
+        assert_eq!(manager.list_backups().unwrap().len(), 1);
+        assert_eq!(manager.load().unwrap().display.position, (400, 0));
+
+        // A real change is backed up, along with the position it was saved with
+        config.display.transparency = 0.5;
+        manager.save(&config).unwrap();
+        assert_eq!(manager.list_backups().unwrap().len(), 2);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_invalid_backup_is_not_restored() {
+        let (dir, manager) = temp_manager("bad_backup");
+        manager.save(&Configuration::default()).unwrap();
+        let live = fs::read_to_string(manager.path()).unwrap();
+
+        let broken = manager.backup_dir().join("config-99991231-235959-999.json");
+        fs::write(&broken, live.replace("\"transparency\": 0.3", "\"transparency\": 7.0")).unwrap();
+        assert!(matches!(manager.restore_backup("1"), Err(ConfigError::ValidationFailed(_))));
+
+        fs::write(&broken, "{ \"version\": ").unwrap();
+        assert!(matches!(manager.restore_backup("1"), Err(ConfigError::InvalidFormat(_))));
+
+        // The live file is untouched
+        assert_eq!(fs::read_to_string(manager.path()).unwrap(), live);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_old_file_is_upgraded_and_backed_up() {
+        let (dir, manager) = temp_manager("migrate");
+        let original = include_str!("../../tests/fixtures/config/v1.0-baseline.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(manager.path(), original).unwrap();
+
+        let (config, report) = manager.load_with_report().unwrap();
+        assert!(report.migration.upgraded());
+        assert_eq!(config.version, crate::models::config::CONFIG_VERSION);
+        assert_eq!(config.notifications.snooze_duration, "5m");
+
+        // The original is kept and the upgraded file loads without further migration
+        assert_eq!(fs::read_to_string(manager.migration_backup_path("1.0")).unwrap(), original);
+        let (_, report) = manager.load_with_report().unwrap();
+        assert!(!report.migration.upgraded());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_reported() {
+        let (dir, manager) = temp_manager("unknown");
+        let mut value = serde_json::to_value(Configuration::default()).unwrap();
+        value["display"]["font"] = Value::from("Consolas");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(manager.path(), serde_json::to_string(&value).unwrap()).unwrap();
+
+        let (_, report) = manager.load_with_report().unwrap();
+        assert_eq!(report.migration.warnings, vec!["unknown field `display.font` ignored".to_string()]);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_invalid_config_is_not_saved() {
+        let (dir, manager) = temp_manager("invalid");
+        let mut config = Configuration::default();
+        config.display.transparency = -0.5;
+
+        assert!(matches!(manager.save(&config), Err(ConfigError::ValidationFailed(_))));
+        assert!(!manager.exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_undecodable_sound_file_is_reported() {
+        let (dir, manager) = temp_manager("sound");
+        fs::create_dir_all(&dir).unwrap();
+        let sound = dir.join("not_audio.wav");
+        fs::write(&sound, "plain text").unwrap();
+        let mut config = Configuration::default();
+        config.notifications.sound_file = Some(sound.to_string_lossy().into_owned());
+
+        // Kept on disk, but reported when validated or loaded
+        assert!(config.validate().is_empty());
+        assert_eq!(
+            manager.validate(&config),
+            vec![ValidationError::InvalidSoundFile(sound.to_string_lossy().into_owned())]
+        );
+        manager.save(&config).unwrap();
+        let (loaded, report) = manager.load_with_report().unwrap();
+        assert_eq!(loaded.notifications.sound_file, config.notifications.sound_file);
+        assert!(report.migration.warnings.iter().any(|warning| warning.contains("system sound")));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_watcher_reports_outside_edits_only() {
+        let (dir, manager) = temp_manager("watch");
+        manager.save(&Configuration::default()).unwrap();
+        let mut watcher = manager.watcher().with_interval(Duration::ZERO);
+        assert!(watcher.poll(Instant::now()).is_none());
+
+        // Our own save is acknowledged, so it does not come back as an edit
+        let mut config = Configuration::default();
+        config.display.position = (10, 20);
+        manager.save(&config).unwrap();
+        watcher.acknowledge();
+        assert!(watcher.poll(Instant::now()).is_none());
+
+        // An outside edit is read once
+        let text = fs::read_to_string(manager.path()).unwrap();
+        fs::write(manager.path(), text.replace("\"transparency\": 0.3", "\"transparency\": 0.45")).unwrap();
+        let edited = watcher.poll(Instant::now()).unwrap().unwrap();
+        assert_eq!(edited.display.transparency, 0.45);
+        assert_eq!(edited.display.position, (10, 20));
+        assert!(watcher.poll(Instant::now()).is_none());
+
+        // An invalid edit is rejected and the file is left as the user wrote it
+        let invalid = text.replace("\"transparency\": 0.3", "\"transparency\": 4.5");
+        fs::write(manager.path(), &invalid).unwrap();
+        assert!(matches!(watcher.poll(Instant::now()), Some(Err(ConfigError::ValidationFailed(_)))));
+        assert_eq!(fs::read_to_string(manager.path()).unwrap(), invalid);
+
+        fs::remove_file(manager.path()).unwrap();
+        assert!(watcher.poll(Instant::now()).is_none());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_watcher_waits_for_poll_interval() {
+        let (dir, manager) = temp_manager("watch_interval");
+        manager.save(&Configuration::default()).unwrap();
+        let mut watcher = manager.watcher();
+        let start = Instant::now();
+        assert!(watcher.poll(start).is_none());
+
+        fs::write(manager.path(), "{}").unwrap();
+        assert!(watcher.poll(start + CONFIG_POLL_INTERVAL / 2).is_none());
+        assert!(watcher.poll(start + CONFIG_POLL_INTERVAL).is_some());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_toml_config_keeps_comments_and_backups_follow_format() {
+        let (dir, manager) = temp_manager("toml");
+        let manager = ConfigManagerImpl::with_path(manager.path().with_extension("toml"));
+        assert_eq!(manager.format(), ConfigFormat::Toml);
+        manager.save(&Configuration::default()).unwrap();
+
+        let text = fs::read_to_string(manager.path()).unwrap();
+        fs::write(manager.path(), text.replacen("[hotkeys]", "# Shortcuts I use most\n[hotkeys]", 1)).unwrap();
+        let mut config = manager.load().unwrap();
+        config.hotkeys.start_stop = Some("Ctrl+Shift+Space".to_string());
+        manager.save(&config).unwrap();
+
+        let saved = fs::read_to_string(manager.path()).unwrap();
+        assert!(saved.contains("# Shortcuts I use most\n[hotkeys]"));
+        assert!(saved.contains("start_stop = \"Ctrl+Shift+Space\""));
+        assert!(manager.list_backups().unwrap()[0].name.ends_with(".toml"));
+        assert_eq!(find_config_file(&dir), manager.path());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_disabled_hotkey_survives_every_format() {
+        for format in ConfigFormat::ALL {
+            let (dir, manager) = temp_manager(&format!("disabled_{}", format));
+            let manager = ConfigManagerImpl::with_path(manager.path().with_extension(format.extension()));
+            let mut config = Configuration::default();
+            config.hotkeys.reset = None;
+            manager.save(&config).unwrap();
+
+            // Loading merges the file over the defaults, which must not bring the hotkey back
+            let (loaded, report) = manager.load_with_report().unwrap();
+            assert!(!report.recovered(), "{}", format);
+            assert_eq!(loaded.hotkeys.reset, None, "{}", format);
+            assert_eq!(manager.load_strict().unwrap().hotkeys.reset, None, "{}", format);
+            assert_eq!(loaded.hotkeys.start_stop, config.hotkeys.start_stop, "{}", format);
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+
+    #[test]
+    fn test_convert_between_formats() {
+        let (dir, manager) = temp_manager("convert");
+        let mut config = Configuration::default();
+        config.display.position = (12, 34);
+        manager.save(&config).unwrap();
+
+        let yaml = manager.convert(ConfigFormat::Yaml).unwrap();
+        assert_eq!(yaml.path(), dir.join("config.yaml"));
+        assert!(!manager.exists());
+        assert_eq!(find_config_file(&dir), yaml.path());
+        assert_eq!(yaml.load().unwrap().display.position, (12, 34));
+
+        // Backups in the old format can still be restored
+        let json_backup = yaml.list_backups().unwrap().into_iter().find(|b| b.name.ends_with(".json")).unwrap();
+        yaml.restore_backup(&json_backup.name).unwrap();
+        assert!(fs::read_to_string(yaml.path()).unwrap().contains("position:"));
+
+        let toml = yaml.convert(ConfigFormat::Toml).unwrap();
+        assert_eq!(toml.load().unwrap().display.position, (12, 34));
+
+        // With two config files present, JSON wins and neither is converted
+        manager.save(&config).unwrap();
+        assert_eq!(config_files(&dir), vec![manager.path().to_path_buf(), toml.path().to_path_buf()]);
+        assert_eq!(find_config_file(&dir), manager.path());
+        assert!(matches!(manager.convert(ConfigFormat::Toml), Err(ConfigError::WriteError(_))));
+        assert!(matches!(manager.convert(ConfigFormat::Yaml), Err(ConfigError::WriteError(_))));
+        assert!(!dir.join("config.yaml").exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    fn quarantined_contents(report: &LoadReport) -> String {
+        fs::read_to_string(report.quarantined.as_ref().expect("damaged file kept")).unwrap()
+    }
+
+    #[test]
+    fn test_invalid_settings_are_salvaged() {
+        let (dir, manager) = temp_manager("salvage");
+        let mut value = serde_json::to_value(Configuration::default()).unwrap();
+        value["display"]["position"] = serde_json::from_str("[640, 20]").unwrap();
+        value["display"]["transparency"] = Value::from(7.0);
+        value["behavior"]["time_adjust_step"] = Value::from(30);
+        let original = serde_json::to_string_pretty(&value).unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(manager.path(), &original).unwrap();
+
+        let (config, report) = manager.load_with_report().unwrap();
+        assert!(report.recovered());
+        let reset: Vec<&str> = report.reset.iter().map(|field| field.path.as_str()).collect();
+        assert_eq!(reset, vec!["display.transparency", "behavior.time_adjust_step"]);
+        assert_eq!(config.display.position, (640, 20));
+        assert_eq!(quarantined_contents(&report), original);
+
+        // The repaired file is written back and loads cleanly
+        let (reloaded, report) = manager.load_with_report().unwrap();
+        assert!(!report.recovered());
+        assert_eq!(reloaded.display.position, (640, 20));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_unreadable_file_falls_back_to_backup() {
+        let (dir, manager) = temp_manager("unreadable");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(manager.path(), "{ not json").unwrap();
+
+        // Without backups the defaults are used
+        let (config, report) = manager.load_with_report().unwrap();
+        assert!(report.unreadable.is_some());
+        assert_eq!(report.restored_backup, None);
+        assert_eq!(config.display.position, Configuration::default().display.position);
+        assert_eq!(quarantined_contents(&report), "{ not json");
+
+        let mut saved = Configuration::default();
+        saved.display.position = (50, 60);
+        saved.pomodoro.work_minutes = 50;
+        manager.save(&saved).unwrap();
+        fs::write(manager.path(), "{ \"version\": ").unwrap();
+
+        let (config, report) = manager.load_with_report().unwrap();
+        assert!(report.restored_backup.is_some());
+        assert_eq!(config.display.position, (50, 60));
+        assert_eq!(config.pomodoro.work_minutes, 50);
+        assert_eq!(manager.load().unwrap().display.position, (50, 60));
+        assert!(report.notices()[0].contains("restored backup"));
+        let _ = fs::remove_dir_all(dir);
     }
 }
\ No newline at end of file
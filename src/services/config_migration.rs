@@ -0,0 +1,351 @@
+// Schema migrations applied to the raw JSON of a configuration file before it is deserialized
+use serde_json::{json, Map, Value};
+
+use crate::models::config::{ConfigError, CONFIG_VERSION};
+
+/// Version assumed for files written before the `version` field was read
+pub const LEGACY_CONFIG_VERSION: &str = "1.0";
+
+/// One change to the raw JSON; paths are dotted, e.g. `display.time_format`
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationOp {
+    /// Move a field to a new name or section, unless the target is already set
+    Rename { from: &'static str, to: &'static str },
+    /// Add a field with the given value if it is missing
+    Default { path: &'static str, value: Value },
+}
+
+/// Ordered changes that upgrade a configuration from one schema version to the next
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub ops: Vec<MigrationOp>,
+}
+
+/// What happened while bringing a configuration up to date
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MigrationReport {
+    pub from_version: String,
+    pub to_version: String,
+    pub applied: Vec<String>,  // e.g. "1.0 -> 2.0"
+    pub warnings: Vec<String>, // Unknown fields, newer versions
+}
+
+impl MigrationReport {
+    /// True if any migration step changed the file
+    pub fn upgraded(&self) -> bool {
+        !self.applied.is_empty()
+    }
+}
+
+/// All schema migrations, oldest first
+pub fn migrations() -> Vec<Migration> {
+    vec![
+        Migration {
+            // 1.0 files lack every setting added since the first release
+            from: "1.0",
+            to: "2.0",
+            ops: vec![
+                MigrationOp::Default { path: "display.time_format", value: json!("{h}:{mm}:{ss}") },
+                MigrationOp::Default { path: "display.collapse_leading_zeros", value: json!(true) },
+                MigrationOp::Default { path: "behavior.overtime_enabled", value: json!(false) },
+                MigrationOp::Default { path: "behavior.time_adjust_step", value: json!("1m") },
+                MigrationOp::Default { path: "hotkeys.record_lap", value: json!("Ctrl+Alt+L") },
+                MigrationOp::Default { path: "hotkeys.skip_step", value: json!("Ctrl+Alt+N") },
+                MigrationOp::Default { path: "hotkeys.previous_step", value: json!("Ctrl+Alt+B") },
+                MigrationOp::Default { path: "hotkeys.add_time", value: json!("Ctrl+Alt+Up") },
+                MigrationOp::Default { path: "hotkeys.subtract_time", value: json!("Ctrl+Alt+Down") },
+                MigrationOp::Default { path: "hotkeys.acknowledge", value: json!("Ctrl+Alt+A") },
+                MigrationOp::Default { path: "hotkeys.snooze", value: json!("Ctrl+Alt+Z") },
+                MigrationOp::Default { path: "notifications.sound_volume", value: json!(1.0) },
+                MigrationOp::Default { path: "notifications.sound_loop_count", value: json!(1) },
+                MigrationOp::Default { path: "notifications.log_file", value: json!(null) },
+                MigrationOp::Default {
+                    path: "notifications.warnings",
+                    value: json!([
+                        { "remaining": "1m", "actions": ["Color"] },
+                        { "remaining": "10s", "actions": ["Color", "Pulse"] }
+                    ]),
+                },
+                MigrationOp::Default { path: "notifications.repeat_until_acknowledged", value: json!(true) },
+                MigrationOp::Default { path: "notifications.ring_interval", value: json!("10s") },
+                MigrationOp::Default { path: "notifications.max_ring_duration", value: json!("5m") },
+                MigrationOp::Default { path: "notifications.snooze_duration", value: json!("5m") },
+                MigrationOp::Default {
+                    path: "pomodoro",
+                    value: json!({
+                        "enabled": false,
+                        "work_minutes": 25,
+                        "short_break_minutes": 5,
+                        "long_break_minutes": 15,
+                        "long_break_every": 4
+                    }),
+                },
+                MigrationOp::Default { path: "sequences", value: json!([]) },
+            ],
+        },
+        Migration {
+            from: "2.0",
+            to: "2.1",
+            ops: vec![MigrationOp::Default { path: "behavior.config_backups", value: json!(10) }],
+        },
+    ]
+}
+
+/// Upgrade raw configuration JSON to `CONFIG_VERSION` in place
+///
+/// Files without a version are treated as `LEGACY_CONFIG_VERSION`. Files from a
+/// newer release are left untouched and loaded as far as they are understood.
+pub fn migrate(value: &mut Value) -> Result<MigrationReport, ConfigError> {
+    if !value.is_object() {
+        return Err(ConfigError::InvalidFormat("configuration must be a JSON object".to_string()));
+    }
+
+    let mut version = match value.get("version") {
+        None | Some(Value::Null) => LEGACY_CONFIG_VERSION.to_string(),
+        Some(Value::String(version)) => version.clone(),
+        Some(other) => {
+            return Err(ConfigError::InvalidFormat(format!("invalid configuration version: {}", other)))
+        }
+    };
+    let mut report = MigrationReport {
+        from_version: version.clone(),
+        to_version: version.clone(),
+        ..Default::default()
+    };
+
+    if version == CONFIG_VERSION {
+        return Ok(report);
+    }
+    if is_newer(&version, CONFIG_VERSION) {
+        report.warnings.push(format!(
+            "configuration version {} is newer than {}; unsupported settings are ignored",
+            version, CONFIG_VERSION
+        ));
+        return Ok(report);
+    }
+
+    let steps = migrations();
+    while version != CONFIG_VERSION {
+        let step = steps.iter().find(|step| step.from == version).ok_or_else(|| {
+            ConfigError::InvalidFormat(format!("unknown configuration version: {}", version))
+        })?;
+        for op in &step.ops {
+            apply_op(value, op);
+        }
+        report.applied.push(format!("{} -> {}", step.from, step.to));
+        version = step.to.to_string();
+    }
+
+    if let Some(root) = value.as_object_mut() {
+        root.insert("version".to_string(), Value::String(version.clone()));
+    }
+    report.to_version = version;
+    Ok(report)
+}
+
+/// Apply a single migration step to raw JSON
+pub fn apply_op(value: &mut Value, op: &MigrationOp) {
+    match op {
+        MigrationOp::Rename { from, to } => {
+            if lookup(value, to).is_some() {
+                return;
+            }
+            if let Some(moved) = remove(value, from) {
+                insert(value, to, moved);
+            }
+        }
+        MigrationOp::Default { path, value: default } => {
+            if lookup(value, path).is_none() {
+                insert(value, path, default.clone());
+            }
+        }
+    }
+}
+
+/// Fields present in `value` that `template` does not have, as dotted paths
+///
+/// Arrays are checked against their template's first element; `null` template
+/// values (unset options) accept anything.
+pub fn unknown_fields(value: &Value, template: &Value) -> Vec<String> {
+    let mut unknown = Vec::new();
+    collect_unknown(value, template, "", &mut unknown);
+    unknown
+}
+
+fn collect_unknown(value: &Value, template: &Value, prefix: &str, unknown: &mut Vec<String>) {
+    match (value, template) {
+        (Value::Object(fields), Value::Object(known)) => {
+            for (key, field) in fields {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                match known.get(key) {
+                    Some(expected) => collect_unknown(field, expected, &path, unknown),
+                    None => unknown.push(path),
+                }
+            }
+        }
+        (Value::Array(items), Value::Array(known)) => {
+            if let Some(expected) = known.first() {
+                for (index, item) in items.iter().enumerate() {
+                    collect_unknown(item, expected, &format!("{}[{}]", prefix, index), unknown);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |current, key| current.get(key))
+}
+
+fn remove(value: &mut Value, path: &str) -> Option<Value> {
+    let (parent, key) = match path.rsplit_once('.') {
+        Some((parent, key)) => (path_mut(value, parent)?, key),
+        None => (value, path),
+    };
+    parent.as_object_mut()?.remove(key)
+}
+
+fn insert(value: &mut Value, path: &str, field: Value) {
+    let mut current = value;
+    let mut keys = path.split('.').peekable();
+    while let Some(key) = keys.next() {
+        let Some(object) = current.as_object_mut() else {
+            return; // A scalar is in the way; leave it for deserialization to report
+        };
+        if keys.peek().is_none() {
+            object.insert(key.to_string(), field);
+            return;
+        }
+        current = object
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+fn path_mut<'a>(value: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    path.split('.').try_fold(value, |current, key| current.get_mut(key))
+}
+
+/// Compare dotted numeric versions such as "1.0" and "2.1"
+fn is_newer(version: &str, than: &str) -> bool {
+    let parse = |v: &str| -> Vec<u32> { v.split('.').map(|part| part.parse().unwrap_or(0)).collect() };
+    parse(version) > parse(than)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(text: &str) -> Value {
+        serde_json::from_str(text).expect("fixture should be valid JSON")
+    }
+
+    #[test]
+    fn test_baseline_file_gains_new_settings() {
+        let mut value = fixture(include_str!("../../tests/fixtures/config/v1.0-baseline.json"));
+        let report = migrate(&mut value).unwrap();
+
+        assert_eq!(report.from_version, "1.0");
+        assert_eq!(report.to_version, CONFIG_VERSION);
+        assert_eq!(report.applied, vec!["1.0 -> 2.0".to_string(), "2.0 -> 2.1".to_string()]);
+        assert_eq!(value["version"], CONFIG_VERSION);
+
+        // Existing settings are kept, new ones get their defaults
+        assert_eq!(value["display"]["transparency"], 0.3);
+        assert_eq!(value["hotkeys"]["toggle_visibility"], "Ctrl+Alt+T");
+        assert_eq!(value["display"]["time_format"], "{h}:{mm}:{ss}");
+        assert_eq!(value["notifications"]["ring_interval"], "10s");
+        assert_eq!(value["notifications"]["warnings"][1]["actions"][1], "Pulse");
+        assert_eq!(value["pomodoro"]["work_minutes"], 25);
+        assert_eq!(value["sequences"], json!([]));
+        assert_eq!(value["behavior"]["config_backups"], 10);
+    }
+
+    #[test]
+    fn test_partially_upgraded_file_keeps_user_values() {
+        // Written by a release that had added fields without bumping the version
+        let mut value = fixture(include_str!("../../tests/fixtures/config/v1.0-partial.json"));
+        migrate(&mut value).unwrap();
+
+        assert_eq!(value["display"]["time_format"], "{total_minutes} min");
+        assert_eq!(value["behavior"]["overtime_enabled"], true);
+        assert_eq!(value["hotkeys"]["add_time"], json!(null));
+        assert_eq!(value["pomodoro"]["work_minutes"], 50);
+        assert_eq!(value["notifications"]["snooze_duration"], "5m");
+    }
+
+    #[test]
+    fn test_unversioned_file_is_treated_as_legacy() {
+        let mut value = fixture(include_str!("../../tests/fixtures/config/unversioned.json"));
+        let report = migrate(&mut value).unwrap();
+
+        assert_eq!(report.from_version, LEGACY_CONFIG_VERSION);
+        assert!(report.upgraded());
+        assert_eq!(value["version"], CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_current_and_newer_versions_are_not_migrated() {
+        let mut current = json!({ "version": "2.1", "display": {} });
+        let report = migrate(&mut current).unwrap();
+        assert!(!report.upgraded());
+        assert!(report.warnings.is_empty());
+        assert_eq!(current, json!({ "version": "2.1", "display": {} }));
+
+        let mut newer = json!({ "version": "9.1" });
+        let report = migrate(&mut newer).unwrap();
+        assert!(!report.upgraded());
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(newer["version"], "9.1");
+    }
+
+    #[test]
+    fn test_unknown_and_invalid_versions_fail() {
+        assert!(matches!(migrate(&mut json!({ "version": "0.5" })), Err(ConfigError::InvalidFormat(_))));
+        assert!(matches!(migrate(&mut json!({ "version": 1 })), Err(ConfigError::InvalidFormat(_))));
+        assert!(matches!(migrate(&mut json!([1, 2])), Err(ConfigError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_rename_moves_fields_between_sections() {
+        let mut value = json!({ "display": { "opacity": 0.4 }, "behavior": {} });
+        apply_op(&mut value, &MigrationOp::Rename { from: "display.opacity", to: "display.transparency" });
+        assert_eq!(value, json!({ "display": { "transparency": 0.4 }, "behavior": {} }));
+
+        apply_op(&mut value, &MigrationOp::Rename { from: "display.transparency", to: "window.transparency" });
+        assert_eq!(value["window"]["transparency"], 0.4);
+        assert_eq!(value["display"], json!({}));
+
+        // An existing target wins over the old field
+        let mut value = json!({ "a": 1, "b": 2 });
+        apply_op(&mut value, &MigrationOp::Rename { from: "a", to: "b" });
+        assert_eq!(value, json!({ "a": 1, "b": 2 }));
+    }
+
+    #[test]
+    fn test_unknown_fields_are_listed() {
+        let template = json!({
+            "display": { "transparency": 0.3, "text_color": null },
+            "notifications": { "warnings": [{ "remaining": "1m", "actions": [] }] },
+            "sequences": []
+        });
+        let value = json!({
+            "display": { "transparency": 0.5, "text_color": { "r": 1 }, "font": "Mono" },
+            "notifications": { "warnings": [{ "remaining": "5m", "actions": [], "color": "red" }] },
+            "sequences": [{ "name": "HIIT" }],
+            "theme": "dark"
+        });
+
+        assert_eq!(
+            unknown_fields(&value, &template),
+            vec![
+                "display.font".to_string(),
+                "notifications.warnings[0].color".to_string(),
+                "theme".to_string(),
+            ]
+        );
+    }
+}
\ No newline at end of file
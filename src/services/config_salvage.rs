@@ -0,0 +1,247 @@
+// Field-by-field recovery of a configuration file that fails to load or validate
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::config::{sequence_field_errors, Configuration, FieldError, CONFIG_VERSION};
+use crate::models::sequence::TimerSequence;
+
+/// A setting that could not be kept and was reset to its default
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResetField {
+    pub path: String,   // JSON path, e.g. "display.transparency"
+    pub reason: String, // Why the stored value was rejected
+}
+
+impl ResetField {
+    pub fn new(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ResetField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} reset to default: {}", self.path, self.reason)
+    }
+}
+
+/// Rebuild a configuration from raw JSON, keeping every value that deserializes and validates
+///
+/// Each section starts from its defaults and takes the stored fields one at a
+/// time, accepting a field only if the section still deserializes and validates.
+/// Rejected fields are retried until nothing changes, so values that are only
+/// valid together (such as both transparencies raised) survive.
+pub fn salvage(value: &Value) -> (Configuration, Vec<ResetField>) {
+    let defaults = Configuration::default();
+    let mut reset = Vec::new();
+    let empty = Map::new();
+    let root = value.as_object().unwrap_or_else(|| {
+        reset.push(ResetField::new("$", format!("expected an object, found {}", kind(value))));
+        &empty
+    });
+
+    let config = Configuration {
+        version: CONFIG_VERSION.to_string(),
+        display: salvage_section("display", root.get("display"), defaults.display, |s| s.validate_fields(), &mut reset),
+        behavior: salvage_section("behavior", root.get("behavior"), defaults.behavior, |s| s.validate_fields(), &mut reset),
+        hotkeys: salvage_section("hotkeys", root.get("hotkeys"), defaults.hotkeys, |s| s.validate_fields(), &mut reset),
+        notifications: salvage_section(
+            "notifications",
+            root.get("notifications"),
+            defaults.notifications,
+            |s| s.validate_fields(),
+            &mut reset,
+        ),
+        pomodoro: salvage_section("pomodoro", root.get("pomodoro"), defaults.pomodoro, |s| s.validate_fields(), &mut reset),
+        sequences: salvage_sequences(root.get("sequences"), &mut reset),
+    };
+    (config, reset)
+}
+
+fn salvage_section<T>(
+    name: &str,
+    stored: Option<&Value>,
+    default: T,
+    validate: impl Fn(&T) -> Vec<FieldError>,
+    reset: &mut Vec<ResetField>,
+) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    let Some(stored) = stored else {
+        reset.push(ResetField::new(name, "missing"));
+        return default;
+    };
+    let Some(fields) = stored.as_object() else {
+        reset.push(ResetField::new(name, format!("expected an object, found {}", kind(stored))));
+        return default;
+    };
+    let Ok(Value::Object(mut merged)) = serde_json::to_value(&default) else {
+        return default;
+    };
+
+    for key in merged.keys().filter(|key| !fields.contains_key(*key)) {
+        reset.push(ResetField::new(format!("{}.{}", name, key), "missing"));
+    }
+
+    // Unknown fields are ignored, as in a normal load
+    let mut pending: Vec<(&String, &Value, String)> = fields
+        .iter()
+        .filter(|(key, _)| merged.contains_key(*key))
+        .map(|(key, field)| (key, field, String::new()))
+        .collect();
+    loop {
+        let before = pending.len();
+        let mut rejected = Vec::new();
+        for (key, field, _) in pending {
+            let mut candidate = merged.clone();
+            candidate.insert(key.clone(), field.clone());
+            match check_section(&candidate, &validate) {
+                Ok(()) => merged = candidate,
+                Err(reason) => rejected.push((key, field, reason)),
+            }
+        }
+        pending = rejected;
+        if pending.is_empty() || pending.len() == before {
+            break;
+        }
+    }
+
+    for (key, _, reason) in pending {
+        reset.push(ResetField::new(format!("{}.{}", name, key), reason));
+    }
+    serde_json::from_value(Value::Object(merged)).unwrap_or(default)
+}
+
+/// Why a section built from these fields cannot be used, if it cannot
+fn check_section<T: DeserializeOwned>(
+    fields: &Map<String, Value>,
+    validate: &impl Fn(&T) -> Vec<FieldError>,
+) -> Result<(), String> {
+    let section: T = serde_json::from_value(Value::Object(fields.clone())).map_err(|e| e.to_string())?;
+    match validate(&section).into_iter().next() {
+        Some(error) => Err(error.error.to_string()),
+        None => Ok(()),
+    }
+}
+
+/// Keep every saved sequence that loads and validates on its own
+fn salvage_sequences(stored: Option<&Value>, reset: &mut Vec<ResetField>) -> Vec<TimerSequence> {
+    let Some(stored) = stored else {
+        reset.push(ResetField::new("sequences", "missing"));
+        return Vec::new();
+    };
+    let Some(items) = stored.as_array() else {
+        reset.push(ResetField::new("sequences", format!("expected an array, found {}", kind(stored))));
+        return Vec::new();
+    };
+
+    let mut sequences = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        let path = format!("sequences[{}]", index);
+        match serde_json::from_value::<TimerSequence>(item.clone()) {
+            Ok(sequence) => match sequence_field_errors(&sequence, sequences.len()).into_iter().next() {
+                None => sequences.push(sequence),
+                Some(error) => reset.push(ResetField::new(path, format!("removed: {}", error.error))),
+            },
+            Err(e) => reset.push(ResetField::new(path, format!("removed: {}", e))),
+        }
+    }
+    sequences
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_value() -> Value {
+        serde_json::to_value(Configuration::default()).unwrap()
+    }
+
+    fn paths(reset: &[ResetField]) -> Vec<&str> {
+        reset.iter().map(|field| field.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_valid_configuration_is_kept_whole() {
+        let mut value = config_value();
+        value["display"]["transparency"] = Value::from(0.5);
+        value["hotkeys"]["reset"] = Value::Null;
+
+        let (config, reset) = salvage(&value);
+        assert!(reset.is_empty());
+        assert_eq!(config.display.transparency, 0.5);
+        assert_eq!(config.hotkeys.reset, None);
+    }
+
+    #[test]
+    fn test_only_invalid_fields_are_reset() {
+        let mut value = config_value();
+        value["display"]["position"] = Value::from(vec![Value::from(640), Value::from(20)]);
+        value["display"]["transparency"] = Value::from("mostly");
+        value["behavior"]["time_adjust_step"] = Value::from("forever");
+        value["behavior"]["overtime_enabled"] = Value::from(true);
+        value["notifications"]["sound_volume"] = Value::from(3.0);
+        value["pomodoro"]["work_minutes"] = Value::from(50);
+
+        let (config, reset) = salvage(&value);
+        assert_eq!(
+            paths(&reset),
+            vec!["display.transparency", "behavior.time_adjust_step", "notifications.sound_volume"]
+        );
+        assert!(config.is_valid());
+
+        // Everything else the user set is still there
+        assert_eq!(config.display.position, (640, 20));
+        assert!(config.behavior.overtime_enabled);
+        assert_eq!(config.pomodoro.work_minutes, 50);
+        assert_eq!(config.display.transparency, Configuration::default().display.transparency);
+    }
+
+    #[test]
+    fn test_fields_valid_only_together_are_kept() {
+        // Raising transparency alone would exceed the default hover transparency
+        let mut value = config_value();
+        value["display"]["transparency"] = Value::from(0.9);
+        value["display"]["hover_transparency"] = Value::from(0.95);
+
+        let (config, reset) = salvage(&value);
+        assert!(reset.is_empty());
+        assert_eq!(config.display.transparency, 0.9);
+        assert_eq!(config.display.hover_transparency, 0.95);
+    }
+
+    #[test]
+    fn test_broken_sections_and_sequences() {
+        let mut value = config_value();
+        value["hotkeys"] = Value::from("Ctrl+Alt+T");
+        value["sequences"] = serde_json::from_str(
+            r#"[
+                { "name": "Tabata", "steps": [{ "label": "Work", "duration_secs": 20 }], "repeat": 8 },
+                { "name": "Broken", "steps": [], "repeat": 1 },
+                { "name": 7 }
+            ]"#,
+        )
+        .unwrap();
+        value.as_object_mut().unwrap().remove("pomodoro");
+
+        let (config, reset) = salvage(&value);
+        assert_eq!(paths(&reset), vec!["hotkeys", "pomodoro", "sequences[1]", "sequences[2]"]);
+        assert_eq!(config.hotkeys.toggle_visibility, Configuration::default().hotkeys.toggle_visibility);
+        assert_eq!(config.sequences.len(), 1);
+        assert_eq!(config.sequences[0].name, "Tabata");
+    }
+}
\ No newline at end of file
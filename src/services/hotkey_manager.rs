@@ -1,10 +1,25 @@
 // Hotkey manager service implementation - placeholder
-// This will be implemented in the next phase
+// Registering the bindings with the OS will be implemented in the next phase
 
-pub struct HotkeyManagerImpl;
+use crate::models::config::HotkeyConfig;
+
+#[derive(Debug)]
+pub struct HotkeyManagerImpl {
+    registered: Option<HotkeyConfig>, // Bindings from the last registration
+}
 
 impl HotkeyManagerImpl {
     pub fn new() -> Self {
-        Self
+        Self { registered: None }
+    }
+    
+    /// Register the configured bindings, replacing any registered before
+    pub fn register(&mut self, hotkeys: &HotkeyConfig) {
+        self.registered = Some(hotkeys.clone());
+    }
+    
+    /// Bindings currently registered, if any
+    pub fn registered(&self) -> Option<&HotkeyConfig> {
+        self.registered.as_ref()
     }
 }
\ No newline at end of file
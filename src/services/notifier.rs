@@ -0,0 +1,388 @@
+// Notification backends (sound, flash, desktop, log file) fed from timer events
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::models::config::{NotificationConfig, WarningAction};
+use crate::models::duration_parser::format_duration;
+use crate::models::events::{TimerEvent, TimerEventKind, TimerSource};
+use crate::models::timer::TimerNotifications;
+use crate::services::sound_player::{SoundPlayer, SoundRequest, SystemSound};
+
+/// What a notification is about
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationKind {
+    Finished,
+    Reminder, // Repeated cue of an alarm that is still ringing
+    PhaseChanged,
+    Warning { actions: Vec<WarningAction> },
+}
+
+/// A notification delivered to every backend
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub source: TimerSource,
+    pub title: String,
+    pub body: String,
+}
+
+impl Notification {
+    /// Notification for a timer event, or None for events nobody is notified about
+    pub fn from_event(event: &TimerEvent) -> Option<Self> {
+        let timer = match (event.source, &event.name) {
+            (TimerSource::Main, _) => "The timer".to_string(),
+            (TimerSource::Named(_), Some(name)) => name.clone(),
+            (TimerSource::Named(id), None) => format!("Timer #{}", id),
+        };
+        let (kind, title, body) = match &event.kind {
+            TimerEventKind::Finished => {
+                (NotificationKind::Finished, "Time's up!".to_string(), format!("{} finished", timer))
+            }
+            TimerEventKind::Ringing { .. } => {
+                (NotificationKind::Reminder, "Time's up!".to_string(), format!("{} is still ringing", timer))
+            }
+            TimerEventKind::PhaseChanged { label, automatic: true } => (
+                NotificationKind::PhaseChanged,
+                format!("Next: {}", label),
+                format!("{} moved on to {}", timer, label),
+            ),
+            TimerEventKind::Warning { remaining, actions } => (
+                NotificationKind::Warning { actions: actions.clone() },
+                format!("{} left", format_duration(*remaining)),
+                format!("{} is almost done", timer),
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            kind,
+            source: event.source,
+            title,
+            body,
+        })
+    }
+
+    /// Check if this is a warning that asked for the given action
+    fn is_warning_with(&self, action: WarningAction) -> bool {
+        matches!(&self.kind, NotificationKind::Warning { actions } if actions.contains(&action))
+    }
+}
+
+/// Notification delivery errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotifyError {
+    Unavailable(String),
+    DeliveryFailed(String),
+}
+
+impl std::fmt::Display for NotifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotifyError::Unavailable(msg) => write!(f, "Notifier unavailable: {}", msg),
+            NotifyError::DeliveryFailed(msg) => write!(f, "Notification failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for NotifyError {}
+
+/// A notification backend
+///
+/// Backends receive every notification and ignore the kinds they do not handle.
+pub trait Notifier: Send {
+    /// Short backend name used in logs and tests
+    fn name(&self) -> &'static str;
+
+    /// Deliver a notification
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError>;
+}
+
+/// Plays the configured sound when a countdown finishes and distinct system
+/// sounds for automatic phase changes and warnings
+pub struct SoundNotifier {
+    player: SoundPlayer,
+    config: NotificationConfig,
+}
+
+impl SoundNotifier {
+    pub fn new(player: SoundPlayer, config: NotificationConfig) -> Self {
+        Self { player, config }
+    }
+}
+
+impl Notifier for SoundNotifier {
+    fn name(&self) -> &'static str {
+        "sound"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let request = match &notification.kind {
+            NotificationKind::Finished | NotificationKind::Reminder => {
+                SoundRequest::from_config(&self.config, SystemSound::Default)
+            }
+            NotificationKind::PhaseChanged => SoundRequest::system(SystemSound::Asterisk),
+            NotificationKind::Warning { .. } if notification.is_warning_with(WarningAction::Sound) => {
+                SoundRequest::system(SystemSound::Exclamation)
+            }
+            NotificationKind::Warning { .. } => return Ok(()),
+        };
+        self.player.play(request);
+        Ok(())
+    }
+}
+
+/// Raises a flag the widget polls to flash itself and ask for the user's attention
+pub struct FlashNotifier {
+    flag: Arc<AtomicBool>,
+}
+
+impl FlashNotifier {
+    pub fn new(flag: Arc<AtomicBool>) -> Self {
+        Self { flag }
+    }
+}
+
+impl Notifier for FlashNotifier {
+    fn name(&self) -> &'static str {
+        "flash"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let wants_flash = match notification.kind {
+            NotificationKind::Finished | NotificationKind::Reminder => true,
+            NotificationKind::PhaseChanged => false,
+            NotificationKind::Warning { .. } => notification.is_warning_with(WarningAction::Notification),
+        };
+        if wants_flash {
+            self.flag.store(true, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+}
+
+/// Desktop notifications: freedesktop D-Bus on Linux, toasts on Windows
+///
+/// Shown from a short-lived thread so a slow notification daemon never blocks the UI.
+#[derive(Default)]
+pub struct DesktopNotifier;
+
+impl DesktopNotifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Notifier for DesktopNotifier {
+    fn name(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let wants_desktop = match notification.kind {
+            NotificationKind::Finished | NotificationKind::PhaseChanged => true,
+            NotificationKind::Reminder => false, // Repeats would pile up in the notification center
+            NotificationKind::Warning { .. } => notification.is_warning_with(WarningAction::Notification),
+        };
+        if !wants_desktop {
+            return Ok(());
+        }
+
+        let title = notification.title.clone();
+        let body = notification.body.clone();
+        thread::Builder::new()
+            .name("desktop-notification".to_string())
+            .spawn(move || {
+                let shown = notify_rust::Notification::new()
+                    .appname("GhostTimer")
+                    .summary(&title)
+                    .body(&body)
+                    .show();
+                if let Err(e) = shown {
+                    eprintln!("{}", NotifyError::DeliveryFailed(e.to_string()));
+                }
+            })
+            .map(|_| ())
+            .map_err(|e| NotifyError::Unavailable(e.to_string()))
+    }
+}
+
+/// Appends a timestamped line per notification to a log file
+pub struct LogFileNotifier {
+    path: PathBuf,
+}
+
+impl LogFileNotifier {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Notifier for LogFileNotifier {
+    fn name(&self) -> &'static str {
+        "log_file"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| NotifyError::Unavailable(format!("{} ({})", self.path.display(), e)))?;
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+        writeln!(file, "{} {}: {}", timestamp, notification.title, notification.body)
+            .map_err(|e| NotifyError::DeliveryFailed(e.to_string()))
+    }
+}
+
+/// Records every notification, for tests
+#[derive(Debug, Clone, Default)]
+pub struct RecordingNotifier {
+    notifications: Arc<Mutex<Vec<Notification>>>,
+}
+
+impl RecordingNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Notifications received so far, oldest first
+    pub fn notifications(&self) -> Vec<Notification> {
+        self.notifications.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Kinds of the notifications received so far, oldest first
+    pub fn kinds(&self) -> Vec<NotificationKind> {
+        self.notifications().into_iter().map(|n| n.kind).collect()
+    }
+}
+
+impl Notifier for RecordingNotifier {
+    fn name(&self) -> &'static str {
+        "recorder"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        self.notifications.lock().unwrap_or_else(|e| e.into_inner()).push(notification.clone());
+        Ok(())
+    }
+}
+
+/// Event bus subscriber that turns timer events into notifications for each backend
+#[derive(Default)]
+pub struct NotificationDispatcher {
+    notifiers: Vec<Box<dyn Notifier>>,
+}
+
+impl NotificationDispatcher {
+    /// Dispatcher without any backends
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Backends enabled in configuration; the flash flag is shared with the widget
+    pub fn from_config(config: &NotificationConfig, flash: Arc<AtomicBool>) -> Self {
+        let mut dispatcher = Self::new();
+        if config.sound_enabled {
+            dispatcher.add(Box::new(SoundNotifier::new(SoundPlayer::new(), config.clone())));
+        }
+        if config.visual_flash {
+            dispatcher.add(Box::new(FlashNotifier::new(flash)));
+        }
+        if config.system_notification {
+            dispatcher.add(Box::new(DesktopNotifier::new()));
+        }
+        if let Some(path) = config.log_file.as_ref().filter(|path| !path.is_empty()) {
+            dispatcher.add(Box::new(LogFileNotifier::new(path)));
+        }
+        dispatcher
+    }
+
+    pub fn add(&mut self, notifier: Box<dyn Notifier>) {
+        self.notifiers.push(notifier);
+    }
+
+    /// Names of the active backends, in delivery order
+    pub fn notifier_names(&self) -> Vec<&'static str> {
+        self.notifiers.iter().map(|notifier| notifier.name()).collect()
+    }
+
+    /// Deliver to every backend; a failing backend is logged and does not stop the others
+    pub fn dispatch(&self, notification: &Notification) {
+        for notifier in &self.notifiers {
+            if let Err(e) = notifier.notify(notification) {
+                eprintln!("{} notifier: {}", notifier.name(), e);
+            }
+        }
+    }
+}
+
+impl TimerNotifications for NotificationDispatcher {
+    fn on_event(&self, event: &TimerEvent) {
+        if let Some(notification) = Notification::from_event(event) {
+            self.dispatch(&notification);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn config(sound: bool, flash: bool, desktop: bool, log_file: Option<&str>) -> NotificationConfig {
+        NotificationConfig {
+            sound_enabled: sound,
+            visual_flash: flash,
+            system_notification: desktop,
+            log_file: log_file.map(str::to_string),
+            ..NotificationConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_backends_selected_from_config() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let all = NotificationDispatcher::from_config(&config(true, true, true, Some("ghost.log")), flag.clone());
+        assert_eq!(all.notifier_names(), vec!["sound", "flash", "desktop", "log_file"]);
+
+        let flash_only = NotificationDispatcher::from_config(&config(false, true, false, None), flag);
+        assert_eq!(flash_only.notifier_names(), vec!["flash"]);
+    }
+
+    #[test]
+    fn test_flash_and_log_file_notifiers() {
+        let path = std::env::temp_dir().join(format!("ghost_timer_notify_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let flag = Arc::new(AtomicBool::new(false));
+        let mut dispatcher = NotificationDispatcher::new();
+        dispatcher.add(Box::new(FlashNotifier::new(flag.clone())));
+        dispatcher.add(Box::new(LogFileNotifier::new(&path)));
+
+        // Warnings flash only when they ask for attention
+        dispatcher.on_event(&TimerEvent::new(
+            TimerSource::Main,
+            TimerEventKind::Warning { remaining: Duration::from_secs(60), actions: vec![WarningAction::Color] },
+        ));
+        assert!(!flag.load(Ordering::SeqCst));
+
+        dispatcher.on_event(&TimerEvent {
+            source: TimerSource::Named(3),
+            kind: TimerEventKind::Finished,
+            name: Some("Pasta".to_string()),
+        });
+        assert!(flag.load(Ordering::SeqCst));
+        dispatcher.on_event(&TimerEvent::new(TimerSource::Named(4), TimerEventKind::Ringing { repeats: 1 }));
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("01:00 left: The timer is almost done"));
+        assert!(lines[1].ends_with("Time's up!: Pasta finished"));
+        assert!(lines[2].ends_with("Time's up!: Timer #4 is still ringing"));
+        let _ = std::fs::remove_file(path);
+    }
+}
\ No newline at end of file
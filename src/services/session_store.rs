@@ -0,0 +1,108 @@
+// Persistence of timer session snapshots next to the configuration file
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::config::ConfigError;
+use crate::models::timer_snapshot::SessionSnapshot;
+use crate::services::config_manager::app_config_dir;
+
+/// File name of the session snapshot inside the GhostTimer config directory
+pub const SESSION_FILE_NAME: &str = "session.json";
+
+/// Reads and writes the session snapshot
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    path: PathBuf,
+}
+
+impl SessionStore {
+    /// Store in the platform config directory, e.g. `%APPDATA%/GhostTimer/session.json`
+    pub fn new() -> Self {
+        Self::with_path(app_config_dir().join(SESSION_FILE_NAME))
+    }
+
+    /// Store at an explicit path (used by tests)
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load the last snapshot, or None if there is none
+    pub fn load(&self) -> Result<Option<SessionSnapshot>, ConfigError> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(ConfigError::InvalidFormat(e.to_string())),
+        };
+        serde_json::from_str(&text)
+            .map(Some)
+            .map_err(|e| ConfigError::InvalidFormat(e.to_string()))
+    }
+
+    /// Save a snapshot, writing a temporary file first so a crash never leaves a partial one
+    pub fn save(&self, snapshot: &SessionSnapshot) -> Result<(), ConfigError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        }
+
+        let json = serde_json::to_string_pretty(snapshot)
+            .map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, json).map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        fs::rename(&temp_path, &self.path).map_err(|e| ConfigError::WriteError(e.to_string()))
+    }
+
+    /// Delete the snapshot, if any
+    pub fn clear(&self) -> Result<(), ConfigError> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(ConfigError::WriteError(e.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for SessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::clock::ManualClock;
+    use crate::models::timer::{Timer, TimerControl};
+    use std::time::Duration;
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = std::env::temp_dir().join(format!("ghost_timer_session_{}", std::process::id()));
+        let store = SessionStore::with_path(dir.join(SESSION_FILE_NAME));
+        assert!(store.load().unwrap().is_none());
+
+        let mut timer = Timer::with_clock(ManualClock::shared());
+        timer.start(Duration::from_secs(25 * 60)).unwrap();
+        let snapshot = SessionSnapshot {
+            saved_at_ms: 0,
+            timer: timer.snapshot(),
+            label: Some("Work 1/4".to_string()),
+            pomodoro: None,
+            sequence: None,
+            named_timers: Vec::new(),
+        };
+
+        store.save(&snapshot).unwrap();
+        let loaded = store.load().unwrap().unwrap();
+        assert_eq!(loaded.timer, snapshot.timer);
+        assert_eq!(loaded.label, snapshot.label);
+
+        store.clear().unwrap();
+        assert!(store.load().unwrap().is_none());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,225 @@
+// Notification sound playback: WAV/OGG/MP3 files or the system sound, off the UI thread
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+
+use crate::models::config::NotificationConfig;
+
+/// Built-in cue used when no file is configured or file playback fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemSound {
+    Default,     // Countdown finished
+    Asterisk,    // Phase or step change
+    Exclamation, // Pre-finish warning
+}
+
+/// Sound playback errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum SoundError {
+    FileNotFound(String),
+    UnsupportedFormat(String),
+    NoOutputDevice(String),
+    PlaybackFailed(String),
+}
+
+impl std::fmt::Display for SoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SoundError::FileNotFound(msg) => write!(f, "Sound file not found: {}", msg),
+            SoundError::UnsupportedFormat(msg) => write!(f, "Sound file cannot be decoded: {}", msg),
+            SoundError::NoOutputDevice(msg) => write!(f, "No audio output device: {}", msg),
+            SoundError::PlaybackFailed(msg) => write!(f, "Sound playback failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SoundError {}
+
+/// A sound to play
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundRequest {
+    pub file: Option<PathBuf>, // None plays the fallback system sound
+    pub volume: f32,           // 0.0 (silent) to 1.0 (full)
+    pub loop_count: u32,       // Times the file is played back to back
+    pub fallback: SystemSound,
+}
+
+impl SoundRequest {
+    /// Play only the given system sound
+    pub fn system(sound: SystemSound) -> Self {
+        Self {
+            file: None,
+            volume: 1.0,
+            loop_count: 1,
+            fallback: sound,
+        }
+    }
+
+    /// Play the configured sound file, falling back to the given system sound
+    pub fn from_config(config: &NotificationConfig, fallback: SystemSound) -> Self {
+        Self {
+            file: config.sound_file.as_ref().filter(|path| !path.is_empty()).map(PathBuf::from),
+            volume: config.sound_volume,
+            loop_count: config.sound_loop_count,
+            fallback,
+        }
+    }
+}
+
+/// Check that a file exists and decodes as WAV, OGG or MP3
+pub fn check_decodable(path: &Path) -> Result<(), SoundError> {
+    open_decoder(path).map(|_| ())
+}
+
+fn open_decoder(path: &Path) -> Result<Decoder<BufReader<File>>, SoundError> {
+    let file = File::open(path)
+        .map_err(|e| SoundError::FileNotFound(format!("{} ({})", path.display(), e)))?;
+    Decoder::new(BufReader::new(file))
+        .map_err(|e| SoundError::UnsupportedFormat(format!("{} ({})", path.display(), e)))
+}
+
+/// Plays sounds on a background thread so the UI never waits on audio
+///
+/// The audio device is opened on first use and kept open; when it cannot be
+/// opened, or a file fails to decode, the system sound plays instead and the
+/// reason is logged once.
+#[derive(Debug, Clone)]
+pub struct SoundPlayer {
+    sender: Sender<SoundRequest>,
+}
+
+impl SoundPlayer {
+    /// Start the playback thread
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<SoundRequest>();
+        thread::spawn(move || {
+            let mut output: Option<(OutputStream, OutputStreamHandle)> = None;
+            let mut last_error: Option<SoundError> = None;
+
+            for request in receiver {
+                let Some(path) = request.file.as_deref() else {
+                    play_system_sound(request.fallback);
+                    continue;
+                };
+
+                match play_file(&mut output, path, request.volume, request.loop_count) {
+                    Ok(()) => last_error = None,
+                    Err(e) => {
+                        if last_error.as_ref() != Some(&e) {
+                            eprintln!("{}; using the system sound instead", e);
+                        }
+                        last_error = Some(e);
+                        play_system_sound(request.fallback);
+                    }
+                }
+            }
+        });
+
+        Self { sender }
+    }
+
+    /// Queue a sound; returns immediately
+    pub fn play(&self, request: SoundRequest) {
+        if self.sender.send(request).is_err() {
+            eprintln!("Sound playback thread has stopped");
+        }
+    }
+}
+
+impl Default for SoundPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decode and start a file on the shared output stream without waiting for it to end
+fn play_file(
+    output: &mut Option<(OutputStream, OutputStreamHandle)>,
+    path: &Path,
+    volume: f32,
+    loop_count: u32,
+) -> Result<(), SoundError> {
+    let source = open_decoder(path)?.buffered();
+
+    if output.is_none() {
+        let stream = OutputStream::try_default()
+            .map_err(|e| SoundError::NoOutputDevice(e.to_string()))?;
+        *output = Some(stream);
+    }
+    let Some((_, handle)) = output.as_ref() else {
+        return Err(SoundError::NoOutputDevice("output stream unavailable".to_string()));
+    };
+
+    let sink = Sink::try_new(handle).map_err(|e| SoundError::PlaybackFailed(e.to_string()))?;
+    sink.set_volume(volume.clamp(0.0, 1.0));
+    for _ in 0..loop_count.max(1) {
+        sink.append(source.clone());
+    }
+    sink.detach();
+    Ok(())
+}
+
+/// Play a built-in cue; silent on platforms without one
+pub fn play_system_sound(sound: SystemSound) {
+    #[cfg(windows)]
+    {
+        let beep_type = match sound {
+            SystemSound::Default => 0xFFFFFFFF,     // Default system sound
+            SystemSound::Asterisk => 0x00000040,    // MB_ICONASTERISK
+            SystemSound::Exclamation => 0x00000030, // MB_ICONEXCLAMATION
+        };
+        unsafe {
+            winapi::um::winuser::MessageBeep(beep_type);
+        }
+    }
+    #[cfg(not(windows))]
+    let _ = sound;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal 16-bit mono PCM WAV file with a few silent samples
+    fn wav_bytes() -> Vec<u8> {
+        let samples = [0u8; 16];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + samples.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        bytes.extend_from_slice(&1u16.to_le_bytes()); // Mono
+        bytes.extend_from_slice(&8000u32.to_le_bytes());
+        bytes.extend_from_slice(&16000u32.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&samples);
+        bytes
+    }
+
+    #[test]
+    fn test_check_decodable() {
+        let dir = std::env::temp_dir().join(format!("ghost_timer_sound_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let wav = dir.join("chime.wav");
+        std::fs::write(&wav, wav_bytes()).unwrap();
+        assert_eq!(check_decodable(&wav), Ok(()));
+
+        let text = dir.join("notes.mp3");
+        std::fs::write(&text, "not really audio").unwrap();
+        assert!(matches!(check_decodable(&text), Err(SoundError::UnsupportedFormat(_))));
+
+        let missing = dir.join("missing.ogg");
+        assert!(matches!(check_decodable(&missing), Err(SoundError::FileNotFound(_))));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+// Integration test: Notification dispatch scenario
+// This test checks that finishing a timer triggers exactly the configured notifications
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use ghost_timer::models::{
+    app_state::AppState,
+    clock::ManualClock,
+    config::NotificationConfig,
+};
+use ghost_timer::services::notifier::{
+    NotificationDispatcher, NotificationKind, RecordingNotifier,
+};
+
+#[cfg(test)]
+mod notification_dispatch_tests {
+    use super::*;
+
+    /// Create an app whose notifications go to a recorder and the given backends
+    fn create_test_app(config: NotificationConfig) -> (AppState, Arc<ManualClock>, RecordingNotifier, Arc<AtomicBool>) {
+        let clock = ManualClock::shared();
+        let mut app = AppState::with_clock(clock.clone());
+        let flash = Arc::new(AtomicBool::new(false));
+        let recorder = RecordingNotifier::new();
+        
+        let mut dispatcher = NotificationDispatcher::from_config(&config, flash.clone());
+        dispatcher.add(Box::new(recorder.clone()));
+        app.subscribe(Box::new(dispatcher));
+        (app, clock, recorder, flash)
+    }
+
+    #[test]
+    fn integration_finishing_timer_notifies_once() {
+        let config = NotificationConfig {
+            sound_enabled: false,
+            visual_flash: true,
+            system_notification: false,
+            ..NotificationConfig::default()
+        };
+        let (mut app, clock, recorder, flash) = create_test_app(config);
+        
+        app.start_timer(Duration::from_secs(90)).expect("Timer start should succeed");
+        clock.advance(Duration::from_secs(45));
+        app.tick_timer();
+        assert!(!recorder.kinds().contains(&NotificationKind::Finished), "Timer should still be counting down");
+        assert!(!flash.load(Ordering::SeqCst), "Visual-only warnings should not flash");
+        
+        clock.advance(Duration::from_secs(45));
+        app.tick_timer();
+        
+        // The default 1:00 and 0:10 warnings are visual-only, so only the finish notifies
+        let kinds: Vec<NotificationKind> = recorder.kinds().into_iter()
+            .filter(|kind| !matches!(kind, NotificationKind::Warning { .. }))
+            .collect();
+        assert_eq!(kinds, vec![NotificationKind::Finished], "Finishing should notify exactly once");
+        assert!(flash.load(Ordering::SeqCst), "Visual flash is enabled and should be raised");
+    }
+
+    #[test]
+    fn integration_disabled_backends_stay_quiet() {
+        let config = NotificationConfig {
+            sound_enabled: false,
+            visual_flash: false,
+            system_notification: false,
+            ..NotificationConfig::default()
+        };
+        let (mut app, clock, recorder, flash) = create_test_app(config);
+        
+        app.start_timer(Duration::from_secs(30)).expect("Timer start should succeed");
+        clock.advance(Duration::from_secs(30));
+        app.tick_timer();
+        
+        assert_eq!(recorder.kinds(), vec![NotificationKind::Finished]);
+        assert!(!flash.load(Ordering::SeqCst), "Flash is disabled in configuration");
+    }
+
+    #[test]
+    fn integration_named_timer_notification_uses_its_name() {
+        let config = NotificationConfig {
+            sound_enabled: false,
+            visual_flash: false,
+            system_notification: false,
+            ..NotificationConfig::default()
+        };
+        let (mut app, clock, recorder, _flash) = create_test_app(config);
+        
+        let tea = app.add_named_timer("Tea", Duration::from_secs(180)).expect("Timer should be added");
+        app.start_named_timer(tea).expect("Timer start should succeed");
+        clock.advance(Duration::from_secs(180));
+        app.tick_timer();
+        
+        let bodies: Vec<String> = recorder.notifications().into_iter().map(|n| n.body).collect();
+        assert_eq!(bodies, vec!["Tea finished".to_string()]);
+    }
+}
\ No newline at end of file